@@ -7,7 +7,7 @@ use std::ops::{Add, Sub};
 use std::path::Path;
 
 use life_backend::format;
-use life_backend::{Board, Game, Position};
+use life_backend::Game;
 
 fn workload<T>(game: &Game<T>, steps: usize)
 where
@@ -27,7 +27,7 @@ where
 {
     let handler = format::open(path)?;
     let rule = handler.rule();
-    let board = handler.live_cells().map(Position::try_from).collect::<Result<Board<T>, _>>()?;
+    let board = format::to_board::<T>(handler.as_ref())?;
     let game = Game::new(rule, board);
     c.bench_function(id, |b| b.iter(|| workload(&game, steps)));
     Ok(())
@@ -54,6 +54,7 @@ mod benchmarks {
     create_benchmark_function!(oscillator_centinal_benchmark, "oscillator-centinal", "patterns/centinal.rle", 100);
     create_benchmark_function!(methuselah_bheptomino_benchmark, "methuselah-bheptomino", "patterns/bheptomino.rle", 148);
     create_benchmark_function!(methuselah_rpentomino_benchmark, "methuselah-rpentomino", "patterns/rpentomino.rle", 1103);
+    create_benchmark_function!(sparse_isolated_benchmark, "sparse-isolated", "patterns/sparse_isolated.rle", 1);
 }
 
 criterion_group!(
@@ -65,5 +66,6 @@ criterion_group!(
     benchmarks::oscillator_centinal_benchmark,
     benchmarks::methuselah_bheptomino_benchmark,
     benchmarks::methuselah_rpentomino_benchmark,
+    benchmarks::sparse_isolated_benchmark,
 );
 criterion_main!(benches);
@@ -1,47 +1,146 @@
+// The code expanded from the derive macros of clap is out of the MSRV of this package
+#![allow(clippy::incompatible_msrv)]
+
 use anyhow::Result;
-use clap::Parser;
+use clap::{ArgGroup, Parser};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
 
 use life_backend::format;
-use life_backend::{Board, Game, Position};
+use life_backend::{Game, Position};
 
 use i16 as I;
 
 #[derive(Parser, Debug)]
+#[command(group(ArgGroup::new("stop").multiple(true).args(["until_stable", "until_empty"])))]
 struct Args {
     #[arg(help = "Pattern file path")]
     path: String,
 
-    #[arg(short, long, default_value_t = 0, help = "Target generation")]
+    #[arg(short, long, default_value_t = 0, conflicts_with_all = ["until_stable", "until_empty"], help = "Target generation")]
     generation: usize,
 
     #[arg(short, long, default_value_t = 1, help = "Step size")]
     step_size: usize,
+
+    #[arg(long, help = "Print a fixed-size viewport centered on the center of mass of the board")]
+    follow: bool,
+
+    #[arg(long, default_value_t = 16, help = "Width and height of the viewport used with --follow")]
+    viewport_size: usize,
+
+    #[arg(long, help = "Stop when the pattern settles into still lifes and period-2 oscillators")]
+    until_stable: bool,
+
+    #[arg(long, help = "Stop when the pattern dies out")]
+    until_empty: bool,
+
+    #[arg(
+        long,
+        default_value_t = 10000,
+        requires = "stop",
+        help = "Maximum generation used instead of --generation with --until-stable or --until-empty"
+    )]
+    max_generations: usize,
+}
+
+// The maximum period of the oscillators regarded as settled by --until-stable
+const MAX_STABLE_PERIOD: usize = 2;
+
+// The conditions to stop the simulation before the last generation
+#[derive(Clone, Copy)]
+struct StopConditions {
+    until_stable: bool,
+    until_empty: bool,
+}
+
+// The way to print the board of each generation
+#[derive(Clone, Copy)]
+enum View {
+    BoundingBox,
+    Follow(usize),
 }
 
 fn run(args: Args) -> Result<()> {
     let handler = format::open(&args.path)?;
     let rule = handler.rule();
-    let board = handler.live_cells().map(Position::try_from).collect::<Result<Board<_>, _>>()?;
+    let board = format::to_board(handler.as_ref())?;
     let game = Game::new(rule, board);
-    simulate(game, args.generation, args.step_size);
+    let view = if args.follow { View::Follow(args.viewport_size) } else { View::BoundingBox };
+    let stop = StopConditions {
+        until_stable: args.until_stable,
+        until_empty: args.until_empty,
+    };
+    let generation = if stop.until_stable || stop.until_empty {
+        args.max_generations
+    } else {
+        args.generation
+    };
+    simulate(game, generation, args.step_size, view, stop);
     Ok(())
 }
 
-fn print_game(game: &Game<I>, generation: usize) {
+// Returns the origin of the viewport of the specified size, whose center is the center of mass of the board
+fn viewport_origin(game: &Game<I>, size: usize) -> Position<I> {
+    let (sum_x, sum_y, count) = game.board().iter().fold((0i64, 0i64, 0i64), |(sum_x, sum_y, count), &Position(x, y)| {
+        (sum_x + i64::from(x), sum_y + i64::from(y), count + 1)
+    });
+    let half = i64::try_from(size / 2).unwrap_or(i64::MAX);
+    let origin = |sum: i64| {
+        let center = if count > 0 { sum.div_euclid(count) } else { 0 };
+        center.saturating_sub(half).clamp(i64::from(I::MIN), i64::from(I::MAX)) as I
+    };
+    Position(origin(sum_x), origin(sum_y))
+}
+
+fn print_game(game: &Game<I>, generation: usize, view: View) {
     let bbox = game.board().bounding_box();
     let population = game.board().iter().count();
     println!("Generation {generation}: bounding-box = {bbox}, population = {population}");
-    println!("{game}");
+    match view {
+        View::BoundingBox => println!("{game}"),
+        View::Follow(size) => println!("{}", game.board().to_fixed_grid(size, size, viewport_origin(game, size))),
+    }
 }
 
-fn simulate(mut game: Game<I>, generation: usize, step_size: usize) {
-    for i in 0..generation {
-        if i % step_size == 0 {
-            print_game(&game, i);
+fn simulate(mut game: Game<I>, generation: usize, step_size: usize, view: View, stop: StopConditions) {
+    print_game(&game, 0, view);
+    let mut history = VecDeque::with_capacity(MAX_STABLE_PERIOD + 1);
+    let mut current = 0;
+    while current < generation {
+        // With --until-stable, every generation is compared with the history, otherwise the game advances to the next generation to be printed
+        let target = if stop.until_stable {
+            current + 1
+        } else {
+            (current / step_size + 1) * step_size
+        }
+        .min(generation);
+        if stop.until_stable {
+            history.push_back(game.board().clone());
+            if history.len() > MAX_STABLE_PERIOD {
+                history.pop_front();
+            }
+        }
+        // advance_by() stops when the pattern dies out, and the board stays empty after that
+        let advanced = game.advance_by(target - current);
+        let reason = if stop.until_empty && game.board().iter().next().is_none() {
+            current += advanced;
+            Some("the pattern died out")
+        } else if stop.until_stable && history.contains(game.board()) {
+            current = target;
+            Some("the pattern settled")
+        } else {
+            current = target;
+            None
+        };
+        if current % step_size == 0 || current == generation || reason.is_some() {
+            print_game(&game, current, view);
+        }
+        if let Some(reason) = reason {
+            println!("Stopped at generation {current}: {reason}");
+            return;
         }
-        game.advance();
     }
-    print_game(&game, generation);
 }
 
 fn main() -> Result<()> {
@@ -61,4 +160,53 @@ mod tests {
         assert!(status.success());
         Ok(())
     }
+    #[test]
+    fn diehard_until_empty() -> Result<()> {
+        let output = Command::new("cargo")
+            .args(["run", "--example", "game", "--", "--until-empty", "--step-size=1000", "patterns/diehard.rle"])
+            .output()?;
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout)?;
+        assert!(stdout.contains("Generation 130: "));
+        assert!(stdout.contains("Stopped at generation 130: the pattern died out"));
+        Ok(())
+    }
+    #[test]
+    fn blinker_until_stable() -> Result<()> {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "--example",
+                "game",
+                "--",
+                "--until-stable",
+                "--max-generations=10",
+                "patterns/blinker.rle",
+            ])
+            .output()?;
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout)?;
+        assert!(stdout.contains("Stopped at generation 2: the pattern settled"));
+        Ok(())
+    }
+    #[test]
+    fn conflicting_generation_flags() -> Result<()> {
+        for args in [["--generation=4", "--until-empty"], ["--max-generations=4", "--step-size=1"]] {
+            let status = Command::new("cargo")
+                .args(["run", "--example", "game", "--"])
+                .args(args)
+                .arg("patterns/diehard.rle")
+                .status()?;
+            assert!(!status.success());
+        }
+        Ok(())
+    }
+    #[test]
+    fn glider_follow() -> Result<()> {
+        let status = Command::new("cargo")
+            .args(["run", "--example", "game", "--", "--generation=8", "--follow", "patterns/glider.rle"])
+            .status()?;
+        assert!(status.success());
+        Ok(())
+    }
 }
@@ -0,0 +1,101 @@
+// The code expanded from the derive macros of clap is out of the MSRV of this package
+#![allow(clippy::incompatible_msrv)]
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use life_backend::{Board, Game, Position, Rule};
+
+use i16 as I;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(short, long, default_value_t = 10, help = "Number of soups")]
+    count: usize,
+
+    #[arg(short, long, default_value = "B3/S23", help = "Rule in the birth/survival notation")]
+    rule: Rule,
+
+    #[arg(long, default_value_t = 16, help = "Width and height of each soup")]
+    size: usize,
+
+    #[arg(long, default_value_t = 1000, help = "Number of generations to settle each soup")]
+    settle: usize,
+
+    #[arg(long, default_value_t = 1, help = "Seed of the random number generator")]
+    seed: u64,
+
+    #[arg(long, default_value_t = 10, help = "Number of the most common objects to print")]
+    top: usize,
+}
+
+// A small pseudo-random number generator (xorshift64*), which is enough for generating soups
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn new(seed: u64) -> Self {
+        // The state must not be zero
+        Self(seed.max(1))
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+// Returns a random soup of the specified size, where each cell is alive with probability 1/2
+fn random_soup(rng: &mut Xorshift, size: I) -> Board<I> {
+    (0..size)
+        .flat_map(|y| (0..size).map(move |x| Position(x, y)))
+        .filter(|_| rng.next_u64() >> 63 == 1)
+        .collect()
+}
+
+fn run(args: Args) -> Result<()> {
+    let size = I::try_from(args.size)?;
+    ensure!(size > 0, "The size must be greater than zero");
+    let mut rng = Xorshift::new(args.seed);
+    let mut total = HashMap::new();
+    for _ in 0..args.count {
+        let soup = random_soup(&mut rng, size);
+        let mut game = Game::new(args.rule.clone(), soup);
+        for (apgcode, count) in game.census(args.settle) {
+            *total.entry(apgcode).or_insert(0) += count;
+        }
+    }
+    let mut objects: Vec<_> = total.into_iter().collect();
+    objects.sort_unstable_by(|(lhs_code, lhs_count), (rhs_code, rhs_count)| rhs_count.cmp(lhs_count).then(lhs_code.cmp(rhs_code)));
+    println!(
+        "Rule: {}, soups: {}, objects: {}",
+        args.rule,
+        args.count,
+        objects.iter().map(|(_, count)| count).sum::<usize>()
+    );
+    for (apgcode, count) in objects.iter().take(args.top) {
+        println!("{count:>8} {apgcode}");
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    run(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use std::process::Command;
+    #[test]
+    fn conways_life() -> Result<()> {
+        let status = Command::new("cargo")
+            .args(["run", "--example", "soup", "--", "--count=2", "--settle=300"])
+            .status()?;
+        assert!(status.success());
+        Ok(())
+    }
+}
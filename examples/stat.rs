@@ -0,0 +1,45 @@
+// The code expanded from the derive macros of clap is out of the MSRV of this package
+#![allow(clippy::incompatible_msrv)]
+
+use anyhow::Result;
+use clap::Parser;
+
+use life_backend::format;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(help = "Pattern file path")]
+    path: String,
+}
+
+fn run(args: Args) -> Result<()> {
+    let handler = format::open(&args.path)?;
+    let summary = handler.summary();
+    if let Some(name) = summary.name() {
+        println!("Name: {name}");
+    }
+    println!("Rule: {}", summary.rule());
+    println!("Population: {}", summary.population());
+    println!("Bounding-box: {}", summary.bounding_box());
+    for line in summary.comments() {
+        println!("Comment: {line}");
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    run(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use std::process::Command;
+    #[test]
+    fn glider() -> Result<()> {
+        let status = Command::new("cargo").args(["run", "--example", "stat", "--", "patterns/glider.rle"]).status()?;
+        assert!(status.success());
+        Ok(())
+    }
+}
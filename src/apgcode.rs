@@ -0,0 +1,221 @@
+// Helpers for apgcodes, the canonical identifiers of objects used by Catagolue.
+// The detail of the format is described in:
+//
+// - [Apgcode - LifeWiki](https://conwaylife.com/wiki/Apgcode)
+// - [Extended Wechsler format - LifeWiki](https://conwaylife.com/wiki/Extended_Wechsler_format)
+
+use anyhow::{bail, ensure, Context as _, Result};
+
+// The characters representing the bit patterns of columns in a strip
+const DIGITS: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+
+// The characters representing the number of zeros in the "y" notation, from 4 to 39
+const ZEROS_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+// The height of each strip
+const STRIP_HEIGHT: usize = 5;
+
+// Returns the better one of the specified encodings: the shorter one, or the lexicographically smaller one if both have the same length
+pub(crate) fn better(lhs: String, rhs: String) -> String {
+    if (rhs.len(), &rhs) < (lhs.len(), &lhs) {
+        rhs
+    } else {
+        lhs
+    }
+}
+
+// Returns the canonical encoding of the specified cells in extended Wechsler format, which is the best one among the eight orientations.
+// The cells must be normalized, i.e., the minimum x- and y-coordinate values are zero.
+pub(crate) fn canonical_wechsler(cells: &[(usize, usize)]) -> String {
+    let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+    let orientations: [&dyn Fn(usize, usize) -> (usize, usize); 8] = [
+        &|x, y| (x, y),
+        &|x, y| (width - 1 - x, y),
+        &|x, y| (x, height - 1 - y),
+        &|x, y| (width - 1 - x, height - 1 - y),
+        &|x, y| (y, x),
+        &|x, y| (height - 1 - y, x),
+        &|x, y| (y, width - 1 - x),
+        &|x, y| (height - 1 - y, width - 1 - x),
+    ];
+    orientations
+        .iter()
+        .map(|f| wechsler(&cells.iter().map(|&(x, y)| f(x, y)).collect::<Vec<_>>()))
+        .reduce(better)
+        .unwrap() // this unwrap never panic because orientations is not empty
+}
+
+// Returns the encoding of the specified cells in extended Wechsler format, without considering orientations
+fn wechsler(cells: &[(usize, usize)]) -> String {
+    let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+    let strip_count = (height + STRIP_HEIGHT - 1) / STRIP_HEIGHT;
+    let mut columns = vec![vec![0usize; width]; strip_count];
+    for &(x, y) in cells {
+        columns[y / STRIP_HEIGHT][x] |= 1 << (y % STRIP_HEIGHT);
+    }
+    let strips: Vec<_> = columns.iter().map(|strip| encode_strip(strip)).collect();
+    if strips.is_empty() {
+        "0".to_owned()
+    } else {
+        strips.join("z")
+    }
+}
+
+// Encodes the bit patterns of columns in a strip, compressing the runs of zeros and omitting the trailing zeros
+fn encode_strip(strip: &[usize]) -> String {
+    let mut buf = String::new();
+    let mut zeros = 0;
+    for &value in strip {
+        if value == 0 {
+            zeros += 1;
+        } else {
+            buf += &encode_zeros(zeros);
+            buf.push(DIGITS[value] as char);
+            zeros = 0;
+        }
+    }
+    buf
+}
+
+// Encodes the run of the specified number of zeros
+fn encode_zeros(mut zeros: usize) -> String {
+    let mut buf = String::new();
+    while zeros >= 40 {
+        buf += "yz";
+        zeros -= 39;
+    }
+    match zeros {
+        0 => {}
+        1 => buf.push('0'),
+        2 => buf.push('w'),
+        3 => buf.push('x'),
+        n => {
+            buf.push('y');
+            buf.push(ZEROS_DIGITS[n - 4] as char);
+        }
+    }
+    buf
+}
+
+// Decodes the specified apgcode into the live cells, supporting still lifes ("xs"), oscillators ("xp") and spaceships ("xq").
+// For still lifes, the population in the code must match the decoded cells.
+pub(crate) fn decode(code: &str) -> Result<Vec<(usize, usize)>> {
+    let (prefix, rest) = (code.get(..2), code.get(2..));
+    let (Some(prefix), Some(rest)) = (prefix, rest) else {
+        bail!("The apgcode is too short");
+    };
+    let Some((number, encoding)) = rest.split_once('_') else {
+        bail!("The apgcode has no underscore");
+    };
+    let number: usize = number.parse().context("The number in the apgcode is invalid")?;
+    let cells = decode_wechsler(encoding)?;
+    match prefix {
+        "xs" => ensure!(number == cells.len(), "The population in the apgcode does not match the number of live cells"),
+        "xp" | "xq" => ensure!(number > 0, "The period in the apgcode must be greater than zero"),
+        _ => bail!("The apgcode prefix is not supported"),
+    }
+    Ok(cells)
+}
+
+// Decodes the specified encoding in extended Wechsler format into the live cells
+fn decode_wechsler(encoding: &str) -> Result<Vec<(usize, usize)>> {
+    ensure!(!encoding.is_empty(), "The encoding is empty");
+    let mut cells = Vec::new();
+    let (mut x, mut strip) = (0, 0);
+    let mut chars = encoding.bytes();
+    while let Some(c) = chars.next() {
+        match c {
+            b'w' => x += 2,
+            b'x' => x += 3,
+            b'y' => {
+                let n = chars.next().and_then(|c| ZEROS_DIGITS.iter().position(|&d| d == c));
+                let Some(n) = n else {
+                    bail!("The run of zeros is invalid");
+                };
+                x += n + 4;
+            }
+            b'z' => {
+                x = 0;
+                strip += 1;
+            }
+            _ => {
+                let Some(value) = DIGITS.iter().position(|&d| d == c) else {
+                    bail!("Invalid character found in the encoding");
+                };
+                cells.extend((0..STRIP_HEIGHT).filter(|i| value & (1 << i) != 0).map(|i| (x, strip * STRIP_HEIGHT + i)));
+                x += 1;
+            }
+        }
+    }
+    Ok(cells)
+}
+
+// Unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn parse(rows: &[&str]) -> Vec<(usize, usize)> {
+        rows.iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.chars().enumerate().filter(|&(_, c)| c == 'O').map(move |(x, _)| (x, y)))
+            .collect()
+    }
+    #[test]
+    fn canonical_wechsler_still_lifes() {
+        assert_eq!(canonical_wechsler(&parse(&["OO", "OO"])), "33");
+        assert_eq!(canonical_wechsler(&parse(&[".OO.", "O..O", ".OO."])), "696");
+        assert_eq!(canonical_wechsler(&parse(&[".OO.", "O..O", ".O.O", "..O."])), "2596");
+        assert_eq!(canonical_wechsler(&parse(&["OO.", "O.O", ".O."])), "253");
+        assert_eq!(canonical_wechsler(&parse(&["OO.", "O.O", ".OO"])), "356");
+        assert_eq!(canonical_wechsler(&parse(&[".O.", "O.O", ".O."])), "252");
+    }
+    #[test]
+    fn canonical_wechsler_tall() {
+        let cells = parse(&["O", "O", "O", "O", "O", "O"]);
+        assert_eq!(canonical_wechsler(&cells), "vz1");
+    }
+    #[test]
+    fn decode_round_trip() -> Result<()> {
+        for cells in [parse(&["OO", "OO"]), parse(&["OO.", "O.O", ".O."]), parse(&["O", "O", "O", "O", "O", "O"])] {
+            let encoding = canonical_wechsler(&cells);
+            let result = decode(&format!("xs{}_{}", cells.len(), encoding))?;
+            assert_eq!(canonical_wechsler(&result), encoding);
+        }
+        Ok(())
+    }
+    #[test]
+    fn decode_wechsler_runs_of_zeros() -> Result<()> {
+        assert_eq!(decode_wechsler("1w1")?, vec![(0, 0), (3, 0)]);
+        assert_eq!(decode_wechsler("1x1")?, vec![(0, 0), (4, 0)]);
+        assert_eq!(decode_wechsler("1y01")?, vec![(0, 0), (5, 0)]);
+        assert_eq!(decode_wechsler("1yz1")?, vec![(0, 0), (40, 0)]);
+        assert_eq!(decode_wechsler("1z1")?, vec![(0, 0), (0, 5)]);
+        assert_eq!(decode_wechsler("0")?, vec![]);
+        Ok(())
+    }
+    #[test]
+    fn decode_invalid() {
+        assert!(decode("").is_err());
+        assert!(decode("xs4").is_err());
+        assert!(decode("xs4_").is_err());
+        assert!(decode("xs5_33").is_err());
+        assert!(decode("xp0_7").is_err());
+        assert!(decode("yl4_33").is_err());
+        assert!(decode("xs4_3!").is_err());
+        assert!(decode("xp2_1y").is_err());
+    }
+    #[test]
+    fn encode_zeros_runs() {
+        assert_eq!(encode_zeros(0), "");
+        assert_eq!(encode_zeros(1), "0");
+        assert_eq!(encode_zeros(2), "w");
+        assert_eq!(encode_zeros(3), "x");
+        assert_eq!(encode_zeros(4), "y0");
+        assert_eq!(encode_zeros(39), "yz");
+        assert_eq!(encode_zeros(40), "yz0");
+        assert_eq!(encode_zeros(43), "yzy0");
+    }
+}
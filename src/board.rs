@@ -1,13 +1,17 @@
-use fnv::FnvBuildHasher;
+use fnv::{FnvBuildHasher, FnvHasher};
 use num_iter::range_inclusive;
-use num_traits::{One, ToPrimitive, Zero};
+use num_traits::{Bounded, One, SaturatingAdd, SaturatingSub, ToPrimitive, Zero};
 use std::collections::hash_set;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
-use std::hash::Hash;
-use std::iter::FromIterator;
+use std::hash::{Hash, Hasher};
+use std::iter::{self, FromIterator};
+use std::ops::{Add, Div, Mul, Neg, RangeInclusive, Rem, Sub};
 
-use crate::{BoardRange, Position};
+use crate::format::{self, Plaintext, Rle};
+use crate::{apgcode, BoardRange, CellStorage, Position, Rule, D4};
 
 /// A two-dimensional orthogonal grid map of live/dead cells.
 ///
@@ -25,6 +29,7 @@ use crate::{BoardRange, Position};
 /// ```
 ///
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Board<T>(HashSet<Position<T>, FnvBuildHasher>)
 where
     T: Eq + Hash;
@@ -50,128 +55,1145 @@ where
         Self(HashSet::default())
     }
 
+    /// Creates from the specified string in RLE format.
+    ///
+    /// Returns an error if the string cannot be parsed, or if the position of a live cell cannot be converted into `Position<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let board = Board::<i16>::from_rle("x = 3, y = 3\nbo$2bo$3o!")?; // Glider pattern
+    /// assert_eq!(board.iter().count(), 5);
+    /// assert_eq!(board.contains(&Position(1, 0)), true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_rle(s: &str) -> anyhow::Result<Self>
+    where
+        T: TryFrom<usize>,
+        T::Error: Error + Send + Sync + 'static,
+    {
+        let handler = s.parse::<Rle>()?;
+        format::to_board(&handler)
+    }
+
+    /// Creates from the specified string in Plaintext format.
+    ///
+    /// Returns an error if the string cannot be parsed, or if the position of a live cell cannot be converted into `Position<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let board = Board::<i16>::from_plaintext(".O\n..O\nOOO\n")?; // Glider pattern
+    /// assert_eq!(board.iter().count(), 5);
+    /// assert_eq!(board.contains(&Position(2, 1)), true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_plaintext(s: &str) -> anyhow::Result<Self>
+    where
+        T: TryFrom<usize>,
+        T::Error: Error + Send + Sync + 'static,
+    {
+        let handler = s.parse::<Plaintext>()?;
+        format::to_board(&handler)
+    }
+
     /// Returns `true` if the board contains the specified position.
     ///
     /// # Examples
     ///
     /// ```
     /// use life_backend::{Board, Position};
-    /// let board = Board::<i16>::new();
-    /// assert_eq!(board.contains(&Position(0, 0)), false);
+    /// let board = Board::<i16>::new();
+    /// assert_eq!(board.contains(&Position(0, 0)), false);
+    /// ```
+    ///
+    #[inline]
+    pub fn contains(&self, position: &Position<T>) -> bool {
+        self.0.contains(position)
+    }
+
+    /// Adds the specified position to the board.
+    ///
+    /// Returns whether the position was newly inserted, like as [`insert()`] of [`HashSet`].
+    ///
+    /// [`insert()`]: std::collections::HashSet::insert
+    /// [`HashSet`]: std::collections::HashSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::<i16>::new();
+    /// assert_eq!(board.insert(Position(0, 0)), true);
+    /// assert_eq!(board.contains(&Position(0, 0)), true);
+    /// ```
+    ///
+    #[inline]
+    pub fn insert(&mut self, position: Position<T>) -> bool {
+        self.0.insert(position)
+    }
+
+    /// Removes the specified position from the board.
+    ///
+    /// Returns whether the position was contained in the board, like as [`remove()`] of [`HashSet`].
+    ///
+    /// [`remove()`]: std::collections::HashSet::remove
+    /// [`HashSet`]: std::collections::HashSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::<i16>::new();
+    /// assert_eq!(board.insert(Position(0, 0)), true);
+    /// assert_eq!(board.remove(&Position(0, 0)), true);
+    /// assert_eq!(board.contains(&Position(0, 0)), false);
+    /// ```
+    ///
+    #[inline]
+    pub fn remove(&mut self, position: &Position<T>) -> bool {
+        self.0.remove(position)
+    }
+
+    /// Adds the specified position to the board, and returns the region whose cells must be rechecked in the next generation.
+    ///
+    /// The region is the 3x3 box around the position, i.e., the position and its [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood),
+    /// whose minimum and maximum values saturate at the bounds of `T`.
+    /// If the position is already contained in the board, the board is unchanged and the empty range is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::<i16>::new();
+    /// let dirty = board.insert_dirty(Position(0, 0));
+    /// assert_eq!(dirty.x(), &(-1..=1));
+    /// assert_eq!(dirty.y(), &(-1..=1));
+    /// assert!(board.insert_dirty(Position(0, 0)).is_empty());
+    /// ```
+    ///
+    pub fn insert_dirty(&mut self, position: Position<T>) -> BoardRange<T>
+    where
+        T: Copy + PartialOrd + Zero + One + SaturatingAdd + SaturatingSub,
+    {
+        let region = Self::dirty_region(&position);
+        if self.insert(position) {
+            region
+        } else {
+            BoardRange::new()
+        }
+    }
+
+    /// Removes the specified position from the board, and returns the region whose cells must be rechecked in the next generation.
+    ///
+    /// The region is the same as [`insert_dirty()`].
+    /// If the position is not contained in the board, the board is unchanged and the empty range is returned.
+    ///
+    /// [`insert_dirty()`]: #method.insert_dirty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board: Board<i16> = [Position(0, 0)].iter().collect();
+    /// let dirty = board.remove_dirty(&Position(0, 0));
+    /// assert_eq!(dirty.x(), &(-1..=1));
+    /// assert_eq!(dirty.y(), &(-1..=1));
+    /// assert!(board.remove_dirty(&Position(0, 0)).is_empty());
+    /// ```
+    ///
+    pub fn remove_dirty(&mut self, position: &Position<T>) -> BoardRange<T>
+    where
+        T: Copy + PartialOrd + Zero + One + SaturatingAdd + SaturatingSub,
+    {
+        if self.remove(position) {
+            Self::dirty_region(position)
+        } else {
+            BoardRange::new()
+        }
+    }
+
+    // Returns the 3x3 region around the specified position, used by insert_dirty() and remove_dirty()
+    fn dirty_region(position: &Position<T>) -> BoardRange<T>
+    where
+        T: Copy + PartialOrd + Zero + One + SaturatingAdd + SaturatingSub,
+    {
+        iter::once(position).collect::<BoardRange<_>>().pad(T::one())
+    }
+
+    /// Returns the minimum bounding box of all live cells on the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::new();
+    /// board.insert(Position(-1, 2));
+    /// board.insert(Position(3, -2));
+    /// let bbox = board.bounding_box();
+    /// assert_eq!(bbox.x(), &(-1..=3));
+    /// assert_eq!(bbox.y(), &(-2..=2));
+    /// ```
+    ///
+    #[inline]
+    pub fn bounding_box(&self) -> BoardRange<T>
+    where
+        T: Copy + PartialOrd + Zero + One,
+    {
+        self.0.iter().collect::<BoardRange<_>>()
+    }
+
+    /// Returns the bounding box of the cells whose state may change in the next generation,
+    /// i.e., the minimum bounding box of all live cells expanded by one cell on each side.
+    ///
+    /// The minimum and maximum values saturate at the bounds of `T`. If the board is empty, the empty range is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(-1, 2), Position(3, -2)].iter().collect();
+    /// let bbox = board.active_bounding_box();
+    /// assert_eq!(bbox.x(), &(-2..=4));
+    /// assert_eq!(bbox.y(), &(-3..=3));
+    /// ```
+    ///
+    #[inline]
+    pub fn active_bounding_box(&self) -> BoardRange<T>
+    where
+        T: Copy + PartialOrd + Zero + One + SaturatingAdd + SaturatingSub,
+    {
+        self.bounding_box().pad(T::one())
+    }
+
+    /// Returns the ratio of the number of live cells to the area of the bounding box of the board.
+    ///
+    /// The returned value is in the range `(0.0, 1.0]` for a non-empty board, and `0.0` for an empty board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 1)].iter().collect();
+    /// assert_eq!(board.fill_ratio(), 0.5);
+    /// assert_eq!(Board::<i16>::new().fill_ratio(), 0.0);
+    /// ```
+    ///
+    pub fn fill_ratio(&self) -> f64
+    where
+        T: Copy + PartialOrd + Zero + One + ToPrimitive,
+    {
+        let bbox = self.bounding_box();
+        if bbox.is_empty() {
+            return 0.0;
+        }
+        let length = |range: &RangeInclusive<T>| range.end().to_f64().unwrap() - range.start().to_f64().unwrap() + 1.0; // these unwraps never panic because every primitive integer is convertible into f64
+        self.0.len() as f64 / (length(bbox.x()) * length(bbox.y()))
+    }
+
+    /// Removes all live cells in the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::<i16>::new();
+    /// board.insert(Position(0, 0));
+    /// board.clear();
+    /// assert_eq!(board.contains(&Position(0, 0)), false);
+    /// ```
+    ///
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Retains only the live cell positions specified by the predicate, similar as [`retain()`] of [`HashSet`].
+    ///
+    /// [`retain()`]: std::collections::HashSet::retain
+    /// [`HashSet`]: std::collections::HashSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::<i16>::new();
+    /// board.insert(Position(0, 0));
+    /// board.insert(Position(1, 0));
+    /// board.insert(Position(0, 1));
+    /// board.retain(|&pos| pos.0 == pos.1);
+    /// assert_eq!(board.contains(&Position(0, 0)), true);
+    /// assert_eq!(board.contains(&Position(1, 0)), false);
+    /// assert_eq!(board.contains(&Position(0, 1)), false);
+    /// ```
+    ///
+    #[inline]
+    pub fn retain<F>(&mut self, pred: F)
+    where
+        F: FnMut(&Position<T>) -> bool,
+    {
+        self.0.retain(pred);
+    }
+
+    /// Retains only the live cells whose x- and y-coordinate values are specified by the predicate.
+    ///
+    /// This is the same as [`retain()`], except that the predicate takes the coordinate values of each live cell instead of its position.
+    ///
+    /// [`retain()`]: #method.retain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let mut board = Board::<i16>::new();
+    /// board.insert(Position(0, 0));
+    /// board.insert(Position(1, 0));
+    /// board.insert(Position(0, 1));
+    /// board.retain_cells(|x, y| x == y);
+    /// assert_eq!(board.contains(&Position(0, 0)), true);
+    /// assert_eq!(board.contains(&Position(1, 0)), false);
+    /// assert_eq!(board.contains(&Position(0, 1)), false);
+    /// ```
+    ///
+    #[inline]
+    pub fn retain_cells<F>(&mut self, mut pred: F)
+    where
+        T: Copy,
+        F: FnMut(T, T) -> bool,
+    {
+        self.0.retain(|&Position(x, y)| pred(x, y));
+    }
+
+    /// Returns a new board containing the live cells that have at least one dead cell in the [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood).
+    ///
+    /// Live cells fully surrounded by live cells are excluded, so the result is the outline of the live cells on the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = (0..3).flat_map(|y| (0..3).map(move |x| Position(x, y))).collect();
+    /// let perimeter = board.perimeter();
+    /// assert_eq!(perimeter.iter().count(), 8);
+    /// assert_eq!(perimeter.contains(&Position(1, 1)), false);
+    /// assert_eq!(perimeter.contains(&Position(0, 0)), true);
+    /// ```
+    ///
+    pub fn perimeter(&self) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        self.0
+            .iter()
+            .filter(|pos| pos.moore_neighborhood_positions().any(|neighbor| !self.contains(&neighbor)))
+            .copied()
+            .collect()
+    }
+
+    /// Returns a new board with the pattern shifted by the specified offset on a torus of the specified width and height.
+    ///
+    /// Each coordinate value of the shifted position wraps around modulo `width` or `height`, e.g., a cell shifted off the right edge reappears on the left edge.
+    /// This method is intended for unsigned coordinate types.
+    /// Cells outside the grid are also mapped into the grid modulo `width` and `height`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<u16> = [Position(2, 1)].iter().collect();
+    /// let translated = board.translate_wrapping(1, 2, 3, 3);
+    /// assert_eq!(translated.contains(&Position(0, 0)), true);
+    /// ```
+    ///
+    pub fn translate_wrapping(&self, dx: T, dy: T, width: T, height: T) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+    {
+        // Computes `(v + d) % len` without overflow
+        fn wrapping_add<T>(v: T, d: T, len: T) -> T
+        where
+            T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+        {
+            let (v, d) = (v % len, d % len);
+            let rest = len - d;
+            if v >= rest {
+                v - rest
+            } else {
+                v + d
+            }
+        }
+        self.0
+            .iter()
+            .map(|&Position(x, y)| Position(wrapping_add(x, dx, width), wrapping_add(y, dy, height)))
+            .collect()
+    }
+
+    /// Partitions the live cells on the board into square tiles of the specified size.
+    ///
+    /// The returned map is keyed by tile coordinates `(tx, ty)`, and each value is a board of the live cells in the tile.
+    /// The coordinate convention is as follows:
+    ///
+    /// - The tile `(tx, ty)` covers the positions `(x, y)` where `tx * tile_size <= x < (tx + 1) * tile_size` and `ty * tile_size <= y < (ty + 1) * tile_size`,
+    ///   i.e., the tile coordinates are the floored quotients of the coordinate values divided by `tile_size` (also for negative coordinate values)
+    /// - The positions on each sub-board are local to the tile, i.e., `Position(x - tx * tile_size, y - ty * tile_size)`,
+    ///   so every coordinate value on a sub-board is in the range `0..tile_size`
+    /// - Tiles that contain no live cells are not included in the map
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile_size` is zero, or may panic if `tile_size` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(1, 1), Position(2, 1), Position(-1, 0)].iter().collect();
+    /// let tiles = board.tile(2);
+    /// assert_eq!(tiles.len(), 3);
+    /// assert_eq!(tiles[&(0, 0)].contains(&Position(1, 1)), true);
+    /// assert_eq!(tiles[&(1, 0)].contains(&Position(0, 1)), true);
+    /// assert_eq!(tiles[&(-1, 0)].contains(&Position(1, 0)), true);
+    /// ```
+    ///
+    pub fn tile(&self, tile_size: T) -> HashMap<(T, T), Self>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T> + Zero + One,
+    {
+        // Returns the floored quotient and the remainder of `v / d`
+        fn div_mod_floor<T>(v: T, d: T) -> (T, T)
+        where
+            T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T> + Zero + One,
+        {
+            let (q, r) = (v / d, v % d);
+            if r < T::zero() {
+                (q - T::one(), r + d)
+            } else {
+                (q, r)
+            }
+        }
+        self.0.iter().fold(HashMap::new(), |mut acc, &Position(x, y)| {
+            let (tx, local_x) = div_mod_floor(x, tile_size);
+            let (ty, local_y) = div_mod_floor(y, tile_size);
+            acc.entry((tx, ty)).or_insert_with(Self::new).insert(Position(local_x, local_y));
+            acc
+        })
+    }
+
+    /// Returns a new board of the next generation of the board, based on the specified rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let next = board.step(&rule);
+    /// let expected: Board<i16> = [Position(1, 0), Position(1, 1), Position(1, 2)].iter().collect();
+    /// assert_eq!(next, expected);
+    /// ```
+    ///
+    pub fn step(&self, rule: &Rule) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        let mut buf = Self::new();
+        self.step_into(rule, &mut buf);
+        buf
+    }
+
+    // Writes the next generation of the board into the specified board, reusing its allocation.
+    pub(crate) fn step_into(&self, rule: &Rule, dest: &mut Self)
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        self.step_into_with_scratch(rule, dest, &mut HashMap::default());
+    }
+
+    // Same as step_into(), but counts the neighbours into the specified map instead of a newly allocated one.
+    // The map is cleared before use and left empty on return, keeping its allocation.
+    pub(crate) fn step_into_with_scratch(&self, rule: &Rule, dest: &mut Self, counts: &mut HashMap<Position<T>, (usize, bool), FnvBuildHasher>)
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        dest.clear();
+        // Without any birth condition, only the live cells need their neighbours counted
+        let Some(min_birth) = rule.min_birth_neighbors() else {
+            counts.clear();
+            dest.extend(self.iter().copied().filter(|pos| rule.is_survive(self.live_neighbour_count(pos))));
+            return;
+        };
+        // The neighbours of all candidate cells are counted in a single pass over the live cells,
+        // so a dead cell with fewer than `min_birth` live neighbours is rejected without any lookup of the board
+        self.count_neighbours_into(counts);
+        dest.extend(counts.drain().filter_map(|(pos, (count, alive))| {
+            let next = if alive {
+                rule.is_survive(count)
+            } else {
+                count >= min_birth && rule.is_born(count)
+            };
+            next.then_some(pos)
+        }));
+    }
+
+    // Writes the count of live neighbours and the state of each cell in the active region into the specified map, used by step_into_with_scratch()
+    fn count_neighbours_into(&self, buf: &mut HashMap<Position<T>, (usize, bool), FnvBuildHasher>)
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        buf.clear();
+        buf.reserve(self.0.len() * 9);
+        for pos in &self.0 {
+            buf.entry(*pos).or_insert((0, false)).1 = true;
+            for neighbour in pos.moore_neighborhood_positions() {
+                buf.entry(neighbour).or_insert((0, false)).0 += 1;
+            }
+        }
+    }
+
+    // Returns the count of live neighbours of the specified position.
+    fn live_neighbour_count(&self, position: &Position<T>) -> usize
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        position.moore_neighborhood_positions().filter(|pos| self.contains(pos)).count()
+    }
+
+    /// Returns the count of live neighbors for each cell in the active region of the board.
+    ///
+    /// The active region consists of the live cells and the cells adjacent to them, i.e., the cells whose state may change in the next generation.
+    /// Cells outside the active region, which have no live neighbors and are dead, are not included in the returned map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+    /// let counts = board.neighbor_counts();
+    /// assert_eq!(counts.len(), 12);
+    /// assert_eq!(counts[&Position(0, 0)], 1);
+    /// assert_eq!(counts[&Position(0, 1)], 2);
+    /// assert_eq!(counts[&Position(-1, 1)], 1);
+    /// ```
+    ///
+    pub fn neighbor_counts(&self) -> HashMap<Position<T>, usize>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        let mut buf = HashMap::new();
+        for pos in &self.0 {
+            buf.entry(*pos).or_insert(0);
+            for neighbor in pos.moore_neighborhood_positions() {
+                *buf.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        buf
+    }
+
+    /// Returns a new board containing the live cells that have exactly the specified number of live neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let expected: Board<i16> = [Position(0, 1), Position(2, 1)].iter().collect();
+    /// assert_eq!(board.cells_with_neighbor_count(1), expected);
+    /// ```
+    ///
+    pub fn cells_with_neighbor_count(&self, n: usize) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        self.0.iter().copied().filter(|pos| self.live_neighbour_count(pos) == n).collect()
+    }
+
+    /// Returns the numbers of births and deaths in the next generation based on the specified rule, as `(births, deaths)`,
+    /// without building the board of the next generation.
+    ///
+    /// The numbers are computed from [`neighbor_counts()`], so only the births in the active region are counted.
+    /// Under rules where a new cell is born from zero live neighbors (e.g., `"B0/S8"`), the births outside the active region are not included.
+    ///
+    /// [`neighbor_counts()`]: #method.neighbor_counts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0), Position(1, 1)].iter().collect(); // T-tetromino
+    /// assert_eq!(board.predicted_delta(&rule), (3, 0));
+    /// ```
+    ///
+    pub fn predicted_delta(&self, rule: &Rule) -> (usize, usize)
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        self.neighbor_counts()
+            .into_iter()
+            .fold((0, 0), |(births, deaths), (pos, count)| match (self.contains(&pos), count) {
+                (true, count) if !rule.is_survive(count) => (births, deaths + 1),
+                (false, count) if rule.is_born(count) => (births + 1, deaths),
+                _ => (births, deaths),
+            })
+    }
+
+    /// Creates an owning iterator over the candidate cells whose state may change in the next generation.
+    ///
+    /// The candidate cells consist of the live cells and the cells adjacent to them, the same as the cells considered when advancing a generation.
+    /// Each position is yielded exactly once, even if it is adjacent to multiple live cells. The order of the positions is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+    /// let candidates: Board<_> = board.expand_candidates().collect();
+    /// assert_eq!(candidates.iter().count(), 12);
+    /// assert_eq!(candidates.contains(&Position(0, 0)), true);
+    /// assert_eq!(candidates.contains(&Position(-1, 1)), true);
+    /// assert_eq!(candidates.contains(&Position(3, 0)), false);
+    /// ```
+    ///
+    pub fn expand_candidates(&self) -> impl Iterator<Item = Position<T>>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        let buf: HashSet<_, FnvBuildHasher> = self
+            .0
+            .iter()
+            .flat_map(|pos| iter::once(*pos).chain(pos.moore_neighborhood_positions()))
+            .collect();
+        buf.into_iter()
+    }
+
+    /// Returns `true` if the minimum corner of the bounding box is at `Position(0, 0)`, i.e., the board is anchored at the origin.
+    ///
+    /// A normalized board has no negative coordinate values, so its live cells can be passed to the builders of
+    /// the formats using `usize` coordinates, such as [`RleBuilder`] and [`PlaintextBuilder`], without any offset.
+    /// An empty board is regarded as normalized.
+    /// To normalize a board, use [`translate_to()`] with `Position(0, 0)`.
+    ///
+    /// [`RleBuilder`]: crate::format::RleBuilder
+    /// [`PlaintextBuilder`]: crate::format::PlaintextBuilder
+    /// [`translate_to()`]: #method.translate_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(-1, 2), Position(3, -2)].iter().collect();
+    /// assert_eq!(board.is_normalized(), false);
+    /// assert_eq!(board.translate_to(Position(0, 0)).is_normalized(), true);
+    /// ```
+    ///
+    pub fn is_normalized(&self) -> bool
+    where
+        T: Copy + PartialOrd + Zero + One,
+    {
+        self.bounding_box().top_left().map_or(true, |pos| pos == Position(T::zero(), T::zero()))
+    }
+
+    /// Returns a new board with the pattern shifted so that the minimum corner of its bounding box lands on the specified position.
+    ///
+    /// If the board is empty, returns an empty board.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if a shifted coordinate value overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(-1, 2), Position(3, -2)].iter().collect();
+    /// let translated = board.translate_to(Position(0, 0));
+    /// assert_eq!(translated.contains(&Position(0, 4)), true);
+    /// assert_eq!(translated.contains(&Position(4, 0)), true);
+    /// ```
+    ///
+    pub fn translate_to(&self, origin: Position<T>) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Zero + One,
+    {
+        let bbox = self.bounding_box();
+        if bbox.is_empty() {
+            return Self::new();
+        }
+        let (min_x, min_y) = (*bbox.x().start(), *bbox.y().start());
+        self.0
+            .iter()
+            .map(|&Position(x, y)| Position(x - min_x + origin.0, y - min_y + origin.1))
+            .collect()
+    }
+
+    /// Returns a new board transformed by the specified symmetry of the square around the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if a transformed coordinate value overflows `T`, e.g., negating `T::MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position, D4};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0)].iter().collect();
+    /// let rotated = board.transform(D4::Rotate90);
+    /// let expected: Board<i16> = [Position(0, 0), Position(0, 1), Position(0, 2)].iter().collect();
+    /// assert_eq!(rotated, expected);
+    /// ```
+    ///
+    pub fn transform(&self, sym: D4) -> Self
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T> + Neg<Output = T> + Zero + One,
+    {
+        let m = sym.to_matrix();
+        let origin = Position(T::zero(), T::zero());
+        self.0.iter().map(|pos| pos.transform(m, origin)).collect()
+    }
+
+    /// Returns a new board containing the live cells that are live on exactly one of the board and the other board.
+    ///
+    /// When the boards are consecutive generations, the result is the set of the cells that were born or died.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0)].iter().collect();
+    /// let other: Board<i16> = [Position(1, 0), Position(2, 0), Position(3, 0)].iter().collect();
+    /// let expected: Board<i16> = [Position(0, 0), Position(3, 0)].iter().collect();
+    /// assert_eq!(board.symmetric_difference(&other), expected);
+    /// ```
+    ///
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+    where
+        T: Copy,
+    {
+        self.0.symmetric_difference(&other.0).copied().collect()
+    }
+
+    /// Returns the canonical form of the board, i.e., the smallest board among the eight symmetries of the square (see [`D4`]) of the board,
+    /// each translated so that the minimum x- and y-coordinate values are zero.
+    ///
+    /// The boards are compared by the live cell positions sorted in row-major order (see [`into_sorted_vec()`]), in lexicographic order.
+    /// Two boards have the same canonical form if and only if they are the same object regardless of the orientation and the position.
+    ///
+    /// [`into_sorted_vec()`]: #method.into_sorted_vec
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if a transformed coordinate value overflows `T`, e.g., negating `T::MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position, D4};
+    /// let board: Board<i16> = [Position(5, 5), Position(5, 6), Position(5, 7)].iter().collect();
+    /// let rotated = board.transform(D4::Rotate90);
+    /// assert_eq!(board.canonical_form(), rotated.canonical_form());
+    /// let expected: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0)].iter().collect();
+    /// assert_eq!(board.canonical_form(), expected);
+    /// ```
+    ///
+    pub fn canonical_form(&self) -> Self
+    where
+        T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Zero + One,
+    {
+        let origin = Position(T::zero(), T::zero());
+        D4::ALL
+            .iter()
+            .map(|&sym| {
+                let board = self.transform(sym).translate_to(origin);
+                let key: Vec<_> = board.clone().into_sorted_vec().into_iter().map(|Position(x, y)| (y, x)).collect();
+                (key, board)
+            })
+            .min_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs))
+            .map(|(_, board)| board)
+            .unwrap() // this unwrap never panic because D4::ALL is not empty
+    }
+
+    /// Returns the hash value of the board, which is invariant under translation.
+    ///
+    /// The hash value is computed from the live cells translated so that the minimum x- and y-coordinate values are zero,
+    /// so boards of the same object at different positions have the same hash value.
+    /// The hash value is not invariant under rotation and reflection; use [`canonical_form()`] before hashing if needed.
+    /// The hash function is deterministic, i.e., the hash value does not change between runs.
+    ///
+    /// [`canonical_form()`]: #method.canonical_form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0)].iter().collect();
+    /// let shifted: Board<i16> = [Position(5, 3), Position(6, 3), Position(7, 3)].iter().collect();
+    /// assert_eq!(board.canonical_hash(), shifted.canonical_hash());
+    /// ```
+    ///
+    pub fn canonical_hash(&self) -> u64
+    where
+        T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Zero + One,
+    {
+        let mut hasher = FnvHasher::default();
+        let cells = self.translate_to(Position(T::zero(), T::zero())).into_sorted_vec();
+        cells.len().hash(&mut hasher);
+        for pos in cells {
+            pos.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Returns a new board with the positions of the live cells mapped by the specified function.
+    ///
+    /// If the function maps several positions to the same position, they are merged into a single live cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0)].iter().collect();
+    /// let scaled: Board<i32> = board.map_positions(|Position(x, y)| Position(i32::from(x) * 2, i32::from(y) * 2));
+    /// let expected: Board<i32> = [Position(0, 0), Position(2, 0), Position(4, 0)].iter().collect();
+    /// assert_eq!(scaled, expected);
+    /// ```
+    ///
+    pub fn map_positions<U, F>(&self, f: F) -> Board<U>
+    where
+        T: Copy,
+        U: Eq + Hash,
+        F: Fn(Position<T>) -> Position<U>,
+    {
+        self.0.iter().copied().map(f).collect()
+    }
+
+    /// Returns the connected components of the live cells, where two live cells are connected if they are adjacent in the [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood).
+    ///
+    /// Each component is returned as a new board, and the order of the components is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 1), Position(3, 0)].iter().collect();
+    /// let components = board.connected_components();
+    /// assert_eq!(components.len(), 2);
+    /// assert!(components.iter().any(|c| c.iter().count() == 2 && c.contains(&Position(1, 1))));
+    /// assert!(components.iter().any(|c| c.iter().count() == 1 && c.contains(&Position(3, 0))));
+    /// ```
+    ///
+    pub fn connected_components(&self) -> Vec<Self>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        let mut remaining = self.clone();
+        let mut buf = Vec::new();
+        while let Some(&start) = remaining.iter().next() {
+            remaining.remove(&start);
+            let mut component = Self::new();
+            let mut stack = vec![start];
+            while let Some(pos) = stack.pop() {
+                component.insert(pos);
+                stack.extend(pos.moore_neighborhood_positions().filter(|neighbor| remaining.remove(neighbor)));
+            }
+            buf.push(component);
+        }
+        buf
+    }
+
+    /// Returns the [apgcode](https://conwaylife.com/wiki/Apgcode) of the board as a still life, e.g., `"xs4_33"` for a block.
+    ///
+    /// The code consists of the prefix `"xs"`, the population, an underscore and the encoding of the board in [extended Wechsler format](https://conwaylife.com/wiki/Extended_Wechsler_format).
+    /// The encoding is canonical, i.e., the best one among the eight orientations of the board, so the code is independent of the orientation and the position of the board.
+    /// This method does not check whether the board is actually a still life under any rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(1, 0), Position(2, 0), Position(0, 1), Position(3, 1), Position(1, 2), Position(2, 2)]
+    ///     .iter()
+    ///     .collect(); // Beehive pattern
+    /// assert_eq!(board.apgcode(), "xs6_696");
+    /// ```
+    ///
+    pub fn apgcode(&self) -> String
+    where
+        T: Copy + PartialOrd + Sub<Output = T> + Zero + One + ToPrimitive,
+    {
+        format!("xs{}_{}", self.0.len(), self.canonical_wechsler())
+    }
+
+    /// Creates from the specified [apgcode](https://conwaylife.com/wiki/Apgcode).
+    ///
+    /// The following code families are supported, and the encoding is decoded into the first phase of the pattern,
+    /// placed so that the minimum x- and y-coordinate values are zero:
+    ///
+    /// - `"xs"`: still lifes, e.g., `"xs4_33"` for a block (the population in the code must match the decoded pattern)
+    /// - `"xp"`: oscillators, e.g., `"xp2_7"` for a blinker
+    /// - `"xq"`: spaceships, e.g., `"xq4_153"` for a glider
+    ///
+    /// Other families, such as `"yl"` for linear growth patterns, are not supported.
+    /// Returns an error if the code cannot be parsed, or if the position of a live cell cannot be converted into `Position<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let board = Board::<i16>::from_apgcode("xp2_7")?; // Blinker pattern
+    /// let expected: Board<i16> = [Position(0, 0), Position(0, 1), Position(0, 2)].iter().collect();
+    /// assert_eq!(board, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_apgcode(code: &str) -> anyhow::Result<Self>
+    where
+        T: TryFrom<usize>,
+        T::Error: Error + Send + Sync + 'static,
+    {
+        let board = apgcode::decode(code)?
+            .into_iter()
+            .map(|(x, y)| Position::try_from(Position(x, y)))
+            .collect::<Result<Self, _>>()?;
+        Ok(board)
+    }
+
+    // Returns the canonical encoding of the board in extended Wechsler format, used by apgcode() and Game::census()
+    pub(crate) fn canonical_wechsler(&self) -> String
+    where
+        T: Copy + PartialOrd + Sub<Output = T> + Zero + One + ToPrimitive,
+    {
+        let bbox = self.bounding_box();
+        let cells: Vec<_> = if bbox.is_empty() {
+            Vec::new()
+        } else {
+            let (min_x, min_y) = (*bbox.x().start(), *bbox.y().start());
+            self.0
+                .iter()
+                .map(|&Position(x, y)| {
+                    let local_x = (x - min_x).to_usize().unwrap(); // this unwrap never panic because the difference is in the range of the board
+                    let local_y = (y - min_y).to_usize().unwrap(); // same as above
+                    (local_x, local_y)
+                })
+                .collect()
+        };
+        apgcode::canonical_wechsler(&cells)
+    }
+
+    /// Consumes the board and returns the live cell positions sorted in row-major order,
+    /// i.e., in ascending order of the y-coordinate value and then in ascending order of the x-coordinate value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(1, 1), Position(0, 1), Position(2, 0)].iter().collect();
+    /// let result = board.into_sorted_vec();
+    /// assert_eq!(result, vec![Position(2, 0), Position(0, 1), Position(1, 1)]);
     /// ```
     ///
-    #[inline]
-    pub fn contains(&self, position: &Position<T>) -> bool {
-        self.0.contains(position)
+    pub fn into_sorted_vec(self) -> Vec<Position<T>>
+    where
+        T: Ord,
+    {
+        let mut buf: Vec<_> = self.0.into_iter().collect();
+        buf.sort_unstable_by(|Position(x0, y0), Position(x1, y1)| y0.cmp(y1).then_with(|| x0.cmp(x1)));
+        buf
     }
 
-    /// Adds the specified position to the board.
+    /// Returns an iterator over the live cell positions as `(x, y)` tuples, sorted in row-major order.
     ///
-    /// Returns whether the position was newly inserted, like as [`insert()`] of [`HashSet`].
+    /// The order is the same as [`Format::live_cells()`], so the output of builders such as [`RleBuilder`] is stable.
     ///
-    /// [`insert()`]: std::collections::HashSet::insert
-    /// [`HashSet`]: std::collections::HashSet
+    /// [`Format::live_cells()`]: crate::format::Format::live_cells
+    /// [`RleBuilder`]: crate::format::RleBuilder
     ///
     /// # Examples
     ///
     /// ```
     /// use life_backend::{Board, Position};
-    /// let mut board = Board::<i16>::new();
-    /// assert_eq!(board.insert(Position(0, 0)), true);
-    /// assert_eq!(board.contains(&Position(0, 0)), true);
+    /// let board: Board<i16> = [Position(1, 1), Position(0, 1), Position(2, 0)].iter().collect();
+    /// assert!(board.live_cells_sorted().eq([(2, 0), (0, 1), (1, 1)]));
     /// ```
     ///
-    #[inline]
-    pub fn insert(&mut self, position: Position<T>) -> bool {
-        self.0.insert(position)
+    pub fn live_cells_sorted(&self) -> impl Iterator<Item = (T, T)>
+    where
+        T: Copy + Ord,
+    {
+        let mut buf: Vec<_> = self.0.iter().map(|&Position(x, y)| (y, x)).collect();
+        buf.sort_unstable();
+        buf.into_iter().map(|(y, x)| (x, y))
     }
 
-    /// Removes the specified position from the board.
+    /// Returns the string of the fixed-size region of the board, which has `width` columns and `height` rows starting at `origin`.
     ///
-    /// Returns whether the position was contained in the board, like as [`remove()`] of [`HashSet`].
+    /// Each row is written in the same way as [`Display`], i.e., `'O'` for a live cell and `'.'` for a dead cell, followed by a newline.
+    /// Unlike [`Display`], the size of the string does not depend on the live cells, and live cells outside the region are not rendered.
     ///
-    /// [`remove()`]: std::collections::HashSet::remove
-    /// [`HashSet`]: std::collections::HashSet
+    /// [`Display`]: std::fmt::Display
     ///
     /// # Examples
     ///
     /// ```
     /// use life_backend::{Board, Position};
-    /// let mut board = Board::<i16>::new();
-    /// assert_eq!(board.insert(Position(0, 0)), true);
-    /// assert_eq!(board.remove(&Position(0, 0)), true);
-    /// assert_eq!(board.contains(&Position(0, 0)), false);
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 1), Position(5, 5)].iter().collect();
+    /// assert_eq!(board.to_fixed_grid(3, 2, Position(-1, 0)), ".O.\n..O\n");
     /// ```
     ///
-    #[inline]
-    pub fn remove(&mut self, position: &Position<T>) -> bool {
-        self.0.remove(position)
+    pub fn to_fixed_grid(&self, width: usize, height: usize, origin: Position<T>) -> String
+    where
+        T: ToPrimitive,
+    {
+        // Converts the coordinate value into the offset from the origin, or None if it is outside the range of 0..len
+        fn offset<T>(v: &T, origin: &T, len: usize) -> Option<usize>
+        where
+            T: ToPrimitive,
+        {
+            let offset = (v.to_i128()? - origin.to_i128()?).to_usize()?;
+            (offset < len).then_some(offset)
+        }
+        let mut grid = vec![vec!['.'; width]; height];
+        for Position(x, y) in &self.0 {
+            if let (Some(col), Some(row)) = (offset(x, &origin.0, width), offset(y, &origin.1, height)) {
+                grid[row][col] = 'O';
+            }
+        }
+        grid.into_iter()
+            .map(|row| row.into_iter().chain(iter::once('\n')).collect::<String>())
+            .collect()
     }
 
-    /// Returns the minimum bounding box of all live cells on the board.
+    /// Returns the string rendering the changes from the board to the other board, over the bounding box of the live cells on both boards.
+    ///
+    /// Each cell is rendered as `born` if it is dead on the board and live on the other board, `died` if it is live on the board and dead on the other board,
+    /// `stayed` if it is live on both boards, and `dead` otherwise.
+    /// Each row is followed by a newline, in the same way as [`Display`].
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Position, Rule};
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let next = board.step(&Rule::conways_life());
+    /// assert_eq!(board.render_diff(&next, '+', '-', 'O', '.'), ".+.\n-O-\n.+.\n");
+    /// ```
+    ///
+    pub fn render_diff(&self, other: &Self, born: char, died: char, stayed: char, dead: char) -> String
+    where
+        T: Copy + PartialOrd + Zero + One + ToPrimitive,
+    {
+        let bbox: BoardRange<_> = self.0.iter().chain(other.0.iter()).collect();
+        let mut buf = String::new();
+        for y in range_inclusive(*bbox.y().start(), *bbox.y().end()) {
+            for x in range_inclusive(*bbox.x().start(), *bbox.x().end()) {
+                let pos = Position(x, y);
+                buf.push(match (self.contains(&pos), other.contains(&pos)) {
+                    (false, true) => born,
+                    (true, false) => died,
+                    (true, true) => stayed,
+                    (false, false) => dead,
+                });
+            }
+            buf.push('\n');
+        }
+        buf
+    }
+
+    /// Returns a new board dilated by the [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood).
+    ///
+    /// Each live cell and its eight neighbors are live on the returned board.
     ///
     /// # Examples
     ///
     /// ```
     /// use life_backend::{Board, Position};
-    /// let mut board = Board::new();
-    /// board.insert(Position(-1, 2));
-    /// board.insert(Position(3, -2));
-    /// let bbox = board.bounding_box();
-    /// assert_eq!(bbox.x(), &(-1..=3));
-    /// assert_eq!(bbox.y(), &(-2..=2));
+    /// let board: Board<i16> = [Position(0, 0)].iter().collect();
+    /// let dilated = board.dilate();
+    /// assert_eq!(dilated.iter().count(), 9);
+    /// let bbox = dilated.bounding_box();
+    /// assert_eq!(bbox.x(), &(-1..=1));
+    /// assert_eq!(bbox.y(), &(-1..=1));
     /// ```
     ///
-    #[inline]
-    pub fn bounding_box(&self) -> BoardRange<T>
+    pub fn dilate(&self) -> Self
     where
-        T: Copy + PartialOrd + Zero + One,
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
     {
-        self.0.iter().collect::<BoardRange<_>>()
+        self.0
+            .iter()
+            .flat_map(|pos| pos.moore_neighborhood_positions())
+            .chain(self.0.iter().copied())
+            .collect()
     }
 
-    /// Removes all live cells in the board.
+    /// Returns a new board eroded by the [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood).
+    ///
+    /// A live cell remains live on the returned board only if all of its eight neighbors are live.
     ///
     /// # Examples
     ///
     /// ```
     /// use life_backend::{Board, Position};
-    /// let mut board = Board::<i16>::new();
-    /// board.insert(Position(0, 0));
-    /// board.clear();
-    /// assert_eq!(board.contains(&Position(0, 0)), false);
+    /// let board: Board<i16> = (0..3).flat_map(|y| (0..3).map(move |x| Position(x, y))).collect();
+    /// let eroded = board.erode();
+    /// assert_eq!(eroded.iter().count(), 1);
+    /// assert_eq!(eroded.contains(&Position(1, 1)), true);
     /// ```
     ///
-    #[inline]
-    pub fn clear(&mut self) {
-        self.0.clear();
+    pub fn erode(&self) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        self.0
+            .iter()
+            .filter(|pos| pos.moore_neighborhood_positions().all(|neighbor| self.contains(&neighbor)))
+            .copied()
+            .collect()
     }
 
-    /// Retains only the live cell positions specified by the predicate, similar as [`retain()`] of [`HashSet`].
+    /// Returns the [Minkowski sum](https://en.wikipedia.org/wiki/Minkowski_addition) of the board and the specified kernel.
     ///
-    /// [`retain()`]: std::collections::HashSet::retain
-    /// [`HashSet`]: std::collections::HashSet
+    /// The returned board is the union of the copies of the board translated by each live cell position of the kernel.
+    /// The computation takes time proportional to the product of the numbers of live cells, so the kernel is expected to be small.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any coordinate of the result overflows and overflow checks are enabled (e.g. in debug builds).
     ///
     /// # Examples
     ///
     /// ```
     /// use life_backend::{Board, Position};
-    /// let mut board = Board::<i16>::new();
-    /// board.insert(Position(0, 0));
-    /// board.insert(Position(1, 0));
-    /// board.insert(Position(0, 1));
-    /// board.retain(|&pos| pos.0 == pos.1);
-    /// assert_eq!(board.contains(&Position(0, 0)), true);
-    /// assert_eq!(board.contains(&Position(1, 0)), false);
-    /// assert_eq!(board.contains(&Position(0, 1)), false);
+    /// let board: Board<i16> = [Position(0, 0), Position(2, 0)].iter().collect();
+    /// let kernel: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+    /// let result = board.minkowski_sum(&kernel);
+    /// let expected: Board<i16> = (0..=3).map(|x| Position(x, 0)).collect();
+    /// assert_eq!(result, expected);
     /// ```
     ///
-    #[inline]
-    pub fn retain<F>(&mut self, pred: F)
+    pub fn minkowski_sum(&self, kernel: &Self) -> Self
     where
-        F: FnMut(&Position<T>) -> bool,
+        T: Copy + Add<Output = T>,
     {
-        self.0.retain(pred);
+        self.0
+            .iter()
+            .flat_map(|&Position(x, y)| kernel.0.iter().map(move |&Position(kx, ky)| Position(x + kx, y + ky)))
+            .collect()
     }
 }
 
@@ -199,6 +1221,27 @@ where
     pub fn iter(&'a self) -> hash_set::Iter<'a, Position<T>> {
         self.into_iter()
     }
+
+    /// Creates a non-owning iterator over the series of live cell positions that are live on the board but not on the other board, in arbitrary order.
+    ///
+    /// Unlike collecting into a new board, this method does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use life_backend::{Board, Position};
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 0)].iter().collect();
+    /// let other: Board<i16> = [Position(1, 0), Position(2, 0), Position(3, 0)].iter().collect();
+    /// let result: HashSet<_> = board.difference_iter(&other).collect();
+    /// assert_eq!(result.len(), 1);
+    /// assert!(result.contains(&Position(0, 0)));
+    /// ```
+    ///
+    #[inline]
+    pub fn difference_iter(&'a self, other: &'a Self) -> hash_set::Difference<'a, Position<T>, FnvBuildHasher> {
+        self.0.difference(&other.0)
+    }
 }
 
 // Trait implementations
@@ -217,6 +1260,43 @@ where
     }
 }
 
+impl<T> CellStorage<T> for Board<T>
+where
+    T: Eq + Hash + Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+{
+    #[inline]
+    fn contains(&self, position: &Position<T>) -> bool {
+        self.contains(position)
+    }
+    #[inline]
+    fn insert(&mut self, position: Position<T>) -> bool {
+        self.insert(position)
+    }
+    #[inline]
+    fn iter(&self) -> Box<dyn Iterator<Item = Position<T>> + '_> {
+        Box::new(self.0.iter().copied())
+    }
+    #[inline]
+    fn clear(&mut self) {
+        self.clear();
+    }
+    #[inline]
+    fn moore_neighbors(&self, position: &Position<T>) -> Box<dyn Iterator<Item = Position<T>> + '_> {
+        Box::new(position.moore_neighborhood_positions())
+    }
+    #[inline]
+    fn retain<F>(&mut self, pred: F)
+    where
+        F: FnMut(&Position<T>) -> bool,
+    {
+        self.retain(pred);
+    }
+    #[inline]
+    fn step_into(&self, rule: &Rule, dst: &mut Self) {
+        Board::step_into(self, rule, dst);
+    }
+}
+
 impl<T> fmt::Display for Board<T>
 where
     T: Eq + Hash + Copy + PartialOrd + Zero + One + ToPrimitive,
@@ -414,10 +1494,473 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anyhow::Result;
     #[test]
     fn default() {
         let target = Board::<i16>::default();
         let expected = Board::<i16>::new();
         assert_eq!(target, expected);
     }
+    #[test]
+    fn retain_cells_sum() {
+        let mut target: Board<i16> = (0..10).flat_map(|y| (0..10).map(move |x| Position(x, y))).collect();
+        target.retain_cells(|x, y| x + y < 10);
+        assert_eq!(target.iter().count(), 55);
+        assert!(target.iter().all(|&Position(x, y)| x + y < 10));
+    }
+    #[test]
+    fn bounding_box_single_min() {
+        let target: Board<i8> = [Position(i8::MIN, i8::MIN)].iter().collect();
+        let result = target.bounding_box();
+        assert!(!result.is_empty());
+        assert_eq!(result.x(), &(i8::MIN..=i8::MIN));
+        assert_eq!(result.y(), &(i8::MIN..=i8::MIN));
+    }
+    #[test]
+    fn insert_and_remove_dirty_moore_neighborhood() {
+        let mut target = Board::<i16>::new();
+        let position = Position(3, -2);
+        let result = target.insert_dirty(position);
+        let expected: BoardRange<i16> = position.moore_neighborhood_positions().collect();
+        assert_eq!(result, expected);
+        assert!(target.contains(&position));
+        assert!(target.insert_dirty(position).is_empty());
+        assert_eq!(target.remove_dirty(&position), expected);
+        assert!(!target.contains(&position));
+        assert!(target.remove_dirty(&position).is_empty());
+    }
+    #[test]
+    fn insert_dirty_saturating() {
+        let mut target = Board::<i8>::new();
+        let result = target.insert_dirty(Position(i8::MIN, i8::MAX));
+        assert_eq!(result.x(), &(i8::MIN..=(i8::MIN + 1)));
+        assert_eq!(result.y(), &((i8::MAX - 1)..=i8::MAX));
+    }
+    #[test]
+    fn is_normalized_true() {
+        let target: Board<i16> = [Position(0, 1), Position(1, 0), Position(2, 2)].iter().collect();
+        assert!(target.is_normalized());
+        assert!(Board::<i16>::new().is_normalized());
+    }
+    #[test]
+    fn is_normalized_false() {
+        let target: Board<i16> = [Position(1, 1), Position(2, 1)].iter().collect();
+        assert!(!target.is_normalized());
+        let target: Board<i16> = [Position(-1, 0), Position(2, 1)].iter().collect();
+        assert!(!target.is_normalized());
+    }
+    #[test]
+    fn active_bounding_box_block() {
+        let target: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        let result = target.active_bounding_box();
+        assert_eq!(result.x(), &(-1..=2));
+        assert_eq!(result.y(), &(-1..=2));
+        assert!(Board::<i16>::new().active_bounding_box().is_empty());
+    }
+    #[test]
+    fn perimeter_square() {
+        let target: Board<i16> = (0..3).flat_map(|y| (0..3).map(move |x| Position(x, y))).collect();
+        let result = target.perimeter();
+        assert_eq!(result.iter().count(), 8);
+        assert!(!result.contains(&Position(1, 1)));
+        assert!(result.iter().all(|pos| target.contains(pos)));
+    }
+    #[test]
+    fn fill_ratio() {
+        let block: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        assert_eq!(block.fill_ratio(), 1.0);
+        let single: Board<i16> = [Position(0, 0)].iter().collect();
+        assert_eq!(single.fill_ratio(), 1.0);
+        let blinker: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        assert_eq!(blinker.fill_ratio(), 1.0);
+        let glider: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        assert_eq!(glider.fill_ratio(), 5.0 / 9.0);
+        assert_eq!(Board::<i16>::new().fill_ratio(), 0.0);
+    }
+    #[test]
+    fn translate_wrapping_right_edge() {
+        let target: Board<u8> = [Position(2, 1)].iter().collect();
+        let result = target.translate_wrapping(1, 0, 3, 3);
+        let expected: Board<u8> = [Position(0, 1)].iter().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn translate_wrapping_large_offset() {
+        let max = u8::MAX;
+        let target: Board<u8> = [Position(max - 1, 0), Position(0, 0)].iter().collect();
+        let result = target.translate_wrapping(max - 1, max, max, 1);
+        let expected: Board<u8> = [Position(max - 2, 0), Position(max - 1, 0)].iter().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn tile_two_tiles() {
+        let target: Board<i16> = [Position(2, 0), Position(3, 0), Position(4, 0), Position(3, 1)].iter().collect();
+        let result = target.tile(4);
+        assert_eq!(result.len(), 2);
+        let expected_left: Board<i16> = [Position(2, 0), Position(3, 0), Position(3, 1)].iter().collect();
+        let expected_right: Board<i16> = [Position(0, 0)].iter().collect();
+        assert_eq!(result[&(0, 0)], expected_left);
+        assert_eq!(result[&(1, 0)], expected_right);
+    }
+    #[test]
+    fn tile_negative_coordinates() {
+        let target: Board<i16> = [Position(-1, -4), Position(-4, -5)].iter().collect();
+        let result = target.tile(4);
+        assert_eq!(result.len(), 2);
+        let expected: Board<i16> = [Position(3, 0)].iter().collect();
+        assert_eq!(result[&(-1, -1)], expected);
+        let expected: Board<i16> = [Position(0, 3)].iter().collect();
+        assert_eq!(result[&(-1, -2)], expected);
+    }
+    #[test]
+    fn step_blinker() {
+        let rule = Rule::conways_life();
+        let target: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        let result = target.step(&rule);
+        let expected: Board<i16> = [Position(1, 0), Position(1, 1), Position(1, 2)].iter().collect();
+        assert_eq!(result, expected);
+        assert_eq!(result.step(&rule), target);
+    }
+    #[test]
+    fn step_isolated_cells_at_distance_two() {
+        let rule: Rule = "B2/S".parse().unwrap(); // Seeds
+        let target: Board<i16> = [Position(0, 0), Position(2, 0), Position(10, 10)].iter().collect();
+        let result = target.step(&rule);
+        let expected: Board<i16> = [Position(1, -1), Position(1, 0), Position(1, 1)].iter().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn step_isolated_cell_with_birth_on_one() {
+        let rule: Rule = "B1/S".parse().unwrap();
+        let target: Board<i16> = [Position(0, 0)].iter().collect();
+        let result = target.step(&rule);
+        assert_eq!(result.iter().count(), 8);
+        assert!(!result.contains(&Position(0, 0)));
+    }
+    #[test]
+    fn step_isolated_cell_at_bounds() {
+        let rule: Rule = "B2/S0".parse().unwrap();
+        let target: Board<i8> = [Position(i8::MAX, i8::MAX), Position(i8::MAX - 2, i8::MAX)].iter().collect();
+        let result = target.step(&rule);
+        let expected: Board<i8> = [
+            Position(i8::MAX, i8::MAX),
+            Position(i8::MAX - 2, i8::MAX),
+            Position(i8::MAX - 1, i8::MAX),
+            Position(i8::MAX - 1, i8::MAX - 1),
+        ]
+        .iter()
+        .collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn difference_iter_overlapping() {
+        let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect(); // Block pattern
+        let other: Board<i16> = [Position(1, 1), Position(2, 1), Position(1, 2), Position(2, 2)].iter().collect();
+        let result: HashSet<_> = board.difference_iter(&other).copied().collect();
+        let expected: HashSet<_> = [Position(0, 0), Position(1, 0), Position(0, 1)].iter().copied().collect();
+        assert_eq!(result, expected);
+        let result: HashSet<_> = other.difference_iter(&board).copied().collect();
+        let expected: HashSet<_> = [Position(2, 1), Position(1, 2), Position(2, 2)].iter().copied().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn neighbor_counts_block() {
+        let target: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        let result = target.neighbor_counts();
+        assert_eq!(result.len(), 16);
+        for pos in target.iter() {
+            assert_eq!(result[pos], 3);
+        }
+        for pos in [Position(-1, -1), Position(2, -1), Position(-1, 2), Position(2, 2)].iter() {
+            assert_eq!(result[pos], 1);
+        }
+        for pos in [Position(0, -1), Position(-1, 0), Position(2, 1), Position(1, 2)].iter() {
+            assert_eq!(result[pos], 2);
+        }
+    }
+    #[test]
+    fn cells_with_neighbor_count_block() {
+        let target: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        assert_eq!(target.cells_with_neighbor_count(3), target);
+        assert_eq!(target.cells_with_neighbor_count(2), Board::new());
+        assert_eq!(Board::<i16>::new().cells_with_neighbor_count(0), Board::new());
+    }
+    #[test]
+    fn predicted_delta_blinker() {
+        let rule = Rule::conways_life();
+        let mut target: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+        for _ in 0..4 {
+            assert_eq!(target.predicted_delta(&rule), (2, 2));
+            target = target.step(&rule);
+        }
+    }
+    #[test]
+    fn predicted_delta_same_as_step() {
+        let rule = Rule::conways_life();
+        let mut target: Board<i16> = [Position(1, 0), Position(2, 0), Position(0, 1), Position(1, 1), Position(1, 2)]
+            .iter()
+            .collect(); // R-pentomino
+        for _ in 0..50 {
+            let next = target.step(&rule);
+            let births = next.iter().filter(|pos| !target.contains(pos)).count();
+            let deaths = target.iter().filter(|pos| !next.contains(pos)).count();
+            assert_eq!(target.predicted_delta(&rule), (births, deaths));
+            target = next;
+        }
+    }
+    #[test]
+    fn neighbor_counts_empty() {
+        let target = Board::<i16>::new();
+        assert!(target.neighbor_counts().is_empty());
+    }
+    #[test]
+    fn expand_candidates_single() {
+        let target: Board<i16> = [Position(0, 0)].iter().collect();
+        let result: HashSet<_> = target.expand_candidates().collect();
+        let expected: HashSet<_> = (-1..=1).flat_map(|y| (-1..=1).map(move |x| Position(x, y))).collect();
+        assert_eq!(result, expected);
+        assert_eq!(target.expand_candidates().count(), 9);
+        assert_eq!(Board::<i16>::new().expand_candidates().count(), 0);
+    }
+    #[test]
+    fn translate_to_block() {
+        let target: Board<i16> = [Position(-3, 5), Position(-2, 5), Position(-3, 6), Position(-2, 6)].iter().collect();
+        let result = target.translate_to(Position(10, 10));
+        let expected: Board<i16> = [Position(10, 10), Position(11, 10), Position(10, 11), Position(11, 11)].iter().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn translate_to_empty() {
+        let target = Board::<i16>::new();
+        assert_eq!(target.translate_to(Position(10, 10)), Board::new());
+    }
+    #[test]
+    fn transform_glider_orbit() {
+        let target: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let origin = Position(0, 0);
+        let orbit: Vec<_> = D4::ALL.iter().map(|&sym| target.transform(sym).translate_to(origin)).collect();
+        let unique: Vec<_> = orbit.iter().enumerate().filter(|(i, board)| !orbit[..*i].contains(board)).collect();
+        assert_eq!(unique.len(), 8);
+        assert_eq!(orbit[0], target);
+        let block: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        assert!(D4::ALL.iter().all(|&sym| block.transform(sym).translate_to(origin) == block));
+    }
+    #[test]
+    fn canonical_form_glider_mirror() {
+        let glider: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let mirror: Board<i16> = glider.iter().map(|&Position(x, y)| Position(10 - x, y + 3)).collect();
+        assert_ne!(glider, mirror);
+        assert_eq!(glider.canonical_form(), mirror.canonical_form());
+        let blinker: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        assert_ne!(glider.canonical_form(), blinker.canonical_form());
+    }
+    #[test]
+    fn canonical_form_empty() {
+        let board = Board::<i16>::new();
+        assert_eq!(board.canonical_form(), board);
+    }
+    #[test]
+    fn canonical_hash_translation() {
+        let glider: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let shifted: Board<i16> = glider.iter().map(|&Position(x, y)| Position(x - 20, y + 7)).collect();
+        assert_eq!(glider.canonical_hash(), shifted.canonical_hash());
+        let mirror: Board<i16> = glider.iter().map(|&Position(x, y)| Position(-x, y)).collect();
+        assert_ne!(glider.canonical_hash(), mirror.canonical_hash());
+        assert_eq!(glider.canonical_form().canonical_hash(), mirror.canonical_form().canonical_hash());
+    }
+    #[test]
+    fn to_fixed_grid_glider() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect(); // Glider pattern
+        let origin = Position(-1, -1);
+        assert_eq!(
+            board.to_fixed_grid(5, 5, origin),
+            concat!(".....\n", "..O..\n", "...O.\n", ".OOO.\n", ".....\n")
+        );
+        let board = board.step(&rule);
+        assert_eq!(
+            board.to_fixed_grid(5, 5, origin),
+            concat!(".....\n", ".....\n", ".O.O.\n", "..OO.\n", "..O..\n")
+        );
+    }
+    #[test]
+    fn to_fixed_grid_empty_region() {
+        let board: Board<i16> = [Position(0, 0)].iter().collect();
+        assert_eq!(board.to_fixed_grid(0, 2, Position(0, 0)), "\n\n");
+        assert_eq!(board.to_fixed_grid(2, 0, Position(0, 0)), "");
+    }
+    #[test]
+    fn from_rle_glider() -> Result<()> {
+        let target = Board::<i16>::from_rle("#N Glider\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n")?;
+        let expected: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        assert_eq!(target, expected);
+        Ok(())
+    }
+    #[test]
+    fn from_plaintext_glider() -> Result<()> {
+        let target = Board::<i16>::from_plaintext("!Name: Glider\n.O.\n..O\nOOO\n")?;
+        let expected: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        assert_eq!(target, expected);
+        Ok(())
+    }
+    #[test]
+    fn from_rle_overflow() {
+        let target = Board::<i8>::from_rle("x = 200, y = 1\n199bo!\n");
+        assert!(target.is_err());
+    }
+    #[test]
+    fn symmetric_difference_blinker_phases() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+        let next = board.step(&rule);
+        let expected: Board<i16> = [Position(0, 1), Position(2, 1), Position(1, 0), Position(1, 2)].iter().collect();
+        assert_eq!(board.symmetric_difference(&next), expected);
+        assert_eq!(next.symmetric_difference(&board), expected);
+        assert_eq!(board.symmetric_difference(&board), Board::new());
+    }
+    #[test]
+    fn render_diff_blinker() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(-1, 0), Position(0, 0), Position(1, 0)].iter().collect(); // Blinker pattern
+        let next = board.step(&rule);
+        assert_eq!(board.render_diff(&next, 'B', 'D', 'S', '_'), concat!("_B_\n", "DSD\n", "_B_\n"));
+        assert_eq!(next.render_diff(&board, 'B', 'D', 'S', '_'), concat!("_D_\n", "BSB\n", "_D_\n"));
+    }
+    #[test]
+    fn render_diff_empty() {
+        let board = Board::<i16>::new();
+        assert_eq!(board.render_diff(&board, 'B', 'D', 'S', '_'), "");
+    }
+    #[test]
+    fn map_positions_scale_blinker() {
+        let board: Board<i16> = [Position(-1, 0), Position(0, 0), Position(1, 0)].iter().collect();
+        let target = board.map_positions(|Position(x, y)| Position(x * 2, y * 2));
+        let expected: Board<i16> = [Position(-2, 0), Position(0, 0), Position(2, 0)].iter().collect();
+        assert_eq!(target, expected);
+    }
+    #[test]
+    fn map_positions_merge() {
+        let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(2, 1)].iter().collect();
+        let target = board.map_positions(|Position(x, y)| Position(x / 2, y / 2));
+        let expected: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+        assert_eq!(target, expected);
+    }
+    #[test]
+    fn connected_components_separated() {
+        let block: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        let blinker: Board<i16> = [Position(3, 0), Position(3, 1), Position(3, 2)].iter().collect();
+        let diagonal: Board<i16> = [Position(0, 5), Position(1, 6), Position(2, 7)].iter().collect();
+        let target: Board<i16> = block.iter().chain(blinker.iter()).chain(diagonal.iter()).collect();
+        let result = target.connected_components();
+        assert_eq!(result.len(), 3);
+        for expected in [block, blinker, diagonal] {
+            assert!(result.contains(&expected));
+        }
+        assert!(Board::<i16>::new().connected_components().is_empty());
+    }
+    #[test]
+    fn apgcode_still_lifes() {
+        let block: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        assert_eq!(block.apgcode(), "xs4_33");
+        let boat: Board<i16> = [Position(-5, -5), Position(-4, -5), Position(-5, -4), Position(-3, -4), Position(-4, -3)]
+            .iter()
+            .collect();
+        assert_eq!(boat.apgcode(), "xs5_253");
+        assert_eq!(boat.transform(D4::FlipDiagonal).apgcode(), "xs5_253");
+        assert_eq!(Board::<i16>::new().apgcode(), "xs0_0");
+    }
+    #[test]
+    fn from_apgcode_block_round_trip() -> Result<()> {
+        let block: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect();
+        let result = Board::<i16>::from_apgcode(&block.apgcode())?;
+        assert_eq!(result, block);
+        Ok(())
+    }
+    #[test]
+    fn from_apgcode_oscillator_and_spaceship() -> Result<()> {
+        let glider = Board::<i16>::from_apgcode("xq4_153")?;
+        assert_eq!(glider.iter().count(), 5);
+        assert_eq!(glider.canonical_wechsler(), "153");
+        let beacon = Board::<i16>::from_apgcode("xp2_318c")?;
+        assert_eq!(beacon.iter().count(), 6);
+        assert_eq!(Board::<i16>::from_apgcode("xs0_0")?, Board::new());
+        Ok(())
+    }
+    #[test]
+    fn from_apgcode_out_of_range() {
+        let result = Board::<i8>::from_apgcode("xp2_1yz1yz1yz1yz1");
+        assert!(result.is_err());
+    }
+    #[test]
+    fn into_sorted_vec_glider() {
+        let target: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let result = target.into_sorted_vec();
+        let expected = vec![Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)];
+        assert_eq!(result, expected);
+        assert!(Board::<i16>::new().into_sorted_vec().is_empty());
+    }
+    #[test]
+    fn live_cells_sorted_matches_plaintext() -> Result<()> {
+        let pattern = "!Name: Glider\n.O.\n..O\nOOO\n";
+        let handler = pattern.parse::<Plaintext>()?;
+        let target = Board::<usize>::from_plaintext(pattern)?;
+        assert!(target.live_cells_sorted().eq(handler.live_cells().map(|Position(x, y)| (x, y))));
+        Ok(())
+    }
+    #[test]
+    fn dilate_single_cell() {
+        let target: Board<i16> = [Position(0, 0)].iter().collect();
+        let result = target.dilate();
+        let expected: Board<i16> = (-1..=1).flat_map(|y| (-1..=1).map(move |x| Position(x, y))).collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn erode_square() {
+        let target: Board<i16> = (0..3).flat_map(|y| (0..3).map(move |x| Position(x, y))).collect();
+        let result = target.erode();
+        let expected: Board<i16> = [Position(1, 1)].iter().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn minkowski_sum_single_cell() {
+        let target: Board<i16> = [Position(0, 0)].iter().collect();
+        let kernel: Board<i16> = [Position(0, -1), Position(-1, 0), Position(0, 0), Position(1, 0), Position(0, 1)]
+            .iter()
+            .collect();
+        let result = target.minkowski_sum(&kernel);
+        assert_eq!(result, kernel);
+    }
+    #[test]
+    fn minkowski_sum_same_as_dilate() {
+        let target: Board<i16> = [Position(0, 0), Position(1, 0), Position(5, 5)].iter().collect();
+        let kernel: Board<i16> = (-1..=1).flat_map(|y| (-1..=1).map(move |x| Position(x, y))).collect();
+        assert_eq!(target.minkowski_sum(&kernel), target.dilate());
+    }
+    #[test]
+    fn minkowski_sum_empty_kernel() {
+        let target: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+        let result = target.minkowski_sum(&Board::new());
+        assert_eq!(result, Board::new());
+    }
+    #[test]
+    fn erode_empty() {
+        let target: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+        assert_eq!(target.erode(), Board::new());
+    }
 }
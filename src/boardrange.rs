@@ -1,4 +1,4 @@
-use num_traits::{One, Zero};
+use num_traits::{One, PrimInt, SaturatingAdd, SaturatingSub, Zero};
 use std::fmt;
 use std::iter::FromIterator;
 use std::ops::RangeInclusive;
@@ -27,6 +27,7 @@ use crate::Position;
 /// ```
 ///
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoardRange<T>(RangeInclusive<T>, RangeInclusive<T>);
 
 // Inherent methods
@@ -153,6 +154,99 @@ impl<T> BoardRange<T> {
     {
         self.x().is_empty()
     }
+
+    /// Returns the range expanded by the specified margin on each side.
+    ///
+    /// The minimum and maximum values saturate at the bounds of `T`. If the range is empty, the empty range is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{BoardRange, Position};
+    /// let range: BoardRange<i8> = [Position(0, 0), Position(2, 127)].iter().collect();
+    /// let padded = range.pad(1);
+    /// assert_eq!(padded.x(), &(-1..=3));
+    /// assert_eq!(padded.y(), &(-1..=127));
+    /// ```
+    ///
+    pub fn pad(&self, margin: T) -> Self
+    where
+        T: Copy + PartialOrd + Zero + One + SaturatingAdd + SaturatingSub,
+    {
+        if self.is_empty() {
+            return Self::new();
+        }
+        let expand = |range: &RangeInclusive<T>| range.start().saturating_sub(&margin)..=range.end().saturating_add(&margin);
+        Self(expand(self.x()), expand(self.y()))
+    }
+
+    /// Returns the top-left corner of the range, i.e., the position of the minimum x- and y-coordinate values.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{BoardRange, Position};
+    /// let range: BoardRange<i16> = [Position(-1, 2), Position(3, -2)].iter().collect();
+    /// assert_eq!(range.top_left(), Some(Position(-1, -2)));
+    /// assert_eq!(BoardRange::<i16>::new().top_left(), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn top_left(&self) -> Option<Position<T>>
+    where
+        T: Copy + PartialOrd,
+    {
+        (!self.is_empty()).then(|| Position(*self.x().start(), *self.y().start()))
+    }
+
+    /// Returns the bottom-right corner of the range, i.e., the position of the maximum x- and y-coordinate values.
+    ///
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{BoardRange, Position};
+    /// let range: BoardRange<i16> = [Position(-1, 2), Position(3, -2)].iter().collect();
+    /// assert_eq!(range.bottom_right(), Some(Position(3, 2)));
+    /// assert_eq!(BoardRange::<i16>::new().bottom_right(), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn bottom_right(&self) -> Option<Position<T>>
+    where
+        T: Copy + PartialOrd,
+    {
+        (!self.is_empty()).then(|| Position(*self.x().end(), *self.y().end()))
+    }
+
+    /// Returns the center of the range.
+    ///
+    /// Each coordinate value is the midpoint of the minimum and maximum values, rounded toward negative infinity.
+    /// Returns `None` if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{BoardRange, Position};
+    /// let range: BoardRange<i16> = [Position(-1, 2), Position(3, -2)].iter().collect();
+    /// assert_eq!(range.center(), Some(Position(1, 0)));
+    /// assert_eq!(BoardRange::<i16>::new().center(), None);
+    /// ```
+    ///
+    pub fn center(&self) -> Option<Position<T>>
+    where
+        T: PrimInt,
+    {
+        // The midpoint is computed without overflow, as the sum of the common bits and the half of the different bits
+        let midpoint = |range: &RangeInclusive<T>| {
+            let (start, end) = (*range.start(), *range.end());
+            (start & end) + ((start ^ end) >> 1)
+        };
+        (!self.is_empty()).then(|| Position(midpoint(self.x()), midpoint(self.y())))
+    }
 }
 
 // Trait implementations
@@ -330,4 +424,50 @@ mod tests {
         let target: BoardRange<_> = positions.iter().collect();
         assert_eq!(format!("{target}"), "(x:[0, 2], y:[0, 1])".to_string());
     }
+    #[test]
+    fn from_iter_single_min() {
+        let target: BoardRange<i8> = [Position(i8::MIN, i8::MIN)].iter().collect();
+        assert!(!target.is_empty());
+        assert_eq!(target.x(), &(i8::MIN..=i8::MIN));
+        assert_eq!(target.y(), &(i8::MIN..=i8::MIN));
+    }
+    #[test]
+    fn from_iter_single_min_unsigned() {
+        let target: BoardRange<u8> = [Position(0, 0)].iter().collect();
+        assert!(!target.is_empty());
+        assert_eq!(target.x(), &(0..=0));
+        assert_eq!(target.y(), &(0..=0));
+    }
+    #[test]
+    fn pad_saturating() {
+        let target: BoardRange<u8> = [Position(0, 1), Position(254, 2)].iter().collect();
+        let result = target.pad(2);
+        assert_eq!(result.x(), &(0..=255));
+        assert_eq!(result.y(), &(0..=4));
+        assert!(BoardRange::<u8>::new().pad(1).is_empty());
+    }
+    #[test]
+    fn corners_and_center() {
+        let target: BoardRange<i16> = [Position(-3, 1), Position(4, 6)].iter().collect();
+        assert_eq!(target.top_left(), Some(Position(-3, 1)));
+        assert_eq!(target.bottom_right(), Some(Position(4, 6)));
+        assert_eq!(target.center(), Some(Position(0, 3)));
+        let empty = BoardRange::<i16>::new();
+        assert_eq!(empty.top_left(), None);
+        assert_eq!(empty.bottom_right(), None);
+        assert_eq!(empty.center(), None);
+    }
+    #[test]
+    fn center_extreme() {
+        let target: BoardRange<i8> = [Position(i8::MIN, i8::MIN), Position(i8::MAX, i8::MIN)].iter().collect();
+        assert_eq!(target.center(), Some(Position(-1, i8::MIN)));
+        let target: BoardRange<u8> = [Position(254, 0), Position(255, 255)].iter().collect();
+        assert_eq!(target.center(), Some(Position(254, 127)));
+    }
+    #[test]
+    fn from_iter_min_max() {
+        let target: BoardRange<i8> = [Position(i8::MAX, i8::MIN), Position(i8::MIN, i8::MAX)].iter().collect();
+        assert_eq!(target.x(), &(i8::MIN..=i8::MAX));
+        assert_eq!(target.y(), &(i8::MIN..=i8::MAX));
+    }
 }
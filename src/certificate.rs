@@ -0,0 +1,168 @@
+use std::hash::Hash;
+
+use crate::Board;
+
+/// The kind of a periodic pattern, detected by [`Game::classify_with_certificate()`].
+///
+/// [`Game::classify_with_certificate()`]: crate::Game::classify_with_certificate
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PatternKind {
+    /// A [still life](https://conwaylife.com/wiki/Still_life), i.e., the pattern does not change.
+    StillLife,
+    /// An [oscillator](https://conwaylife.com/wiki/Oscillator), i.e., the pattern returns to the initial state after two or more generations.
+    Oscillator,
+    /// A [spaceship](https://conwaylife.com/wiki/Spaceship), i.e., the pattern returns to a translation of the initial state.
+    Spaceship,
+}
+
+/// A certificate of a periodic pattern, returned by [`Game::classify_with_certificate()`].
+///
+/// The certificate records the kind, the period, the displacement per period and the boards of all phases of the pattern.
+///
+/// [`Game::classify_with_certificate()`]: crate::Game::classify_with_certificate
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Board, Game, PatternKind, Position, Rule};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let rule = Rule::conways_life();
+/// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+/// let mut game = Game::new(rule, board.clone());
+/// let certificate = game.classify_with_certificate(10)?;
+/// assert_eq!(certificate.kind(), PatternKind::Oscillator);
+/// assert_eq!(certificate.period(), 2);
+/// assert_eq!(certificate.displacement(), (0, 0));
+/// assert_eq!(certificate.phases()[0], board);
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Certificate<T>
+where
+    T: Eq + Hash,
+{
+    kind: PatternKind,
+    displacement: (i64, i64),
+    phases: Vec<Board<T>>,
+}
+
+// Inherent methods
+
+impl<T> Certificate<T>
+where
+    T: Eq + Hash,
+{
+    // Creates a certificate from the displacement per period and the boards of all phases.
+    // The phases must not be empty.
+    pub(crate) fn new(displacement: (i64, i64), phases: Vec<Board<T>>) -> Self {
+        debug_assert!(!phases.is_empty());
+        let kind = if displacement != (0, 0) {
+            PatternKind::Spaceship
+        } else if phases.len() == 1 {
+            PatternKind::StillLife
+        } else {
+            PatternKind::Oscillator
+        };
+        Self { kind, displacement, phases }
+    }
+
+    /// Returns the kind of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, PatternKind, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1)].iter().collect(); // Block pattern
+    /// let mut game = Game::new(rule, board);
+    /// assert_eq!(game.classify_with_certificate(10)?.kind(), PatternKind::StillLife);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn kind(&self) -> PatternKind {
+        self.kind
+    }
+
+    /// Returns the period of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// assert_eq!(game.classify_with_certificate(10)?.period(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn period(&self) -> usize {
+        self.phases.len()
+    }
+
+    /// Returns the displacement of the pattern per period as `(dx, dy)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)].iter().collect(); // Glider pattern
+    /// let mut game = Game::new(rule, board);
+    /// assert_eq!(game.classify_with_certificate(10)?.displacement(), (1, 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn displacement(&self) -> (i64, i64) {
+        self.displacement
+    }
+
+    /// Returns the boards of all phases of the pattern, i.e., the boards of generations `0..period`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board.clone());
+    /// let certificate = game.classify_with_certificate(10)?;
+    /// assert_eq!(certificate.phases().len(), 2);
+    /// assert_eq!(certificate.phases()[0], board);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn phases(&self) -> &[Board<T>] {
+        &self.phases
+    }
+}
+
+// Unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+    #[test]
+    fn new_kinds() {
+        let board: Board<i16> = [Position(0, 0)].iter().collect();
+        assert_eq!(Certificate::new((0, 0), vec![board.clone()]).kind(), PatternKind::StillLife);
+        assert_eq!(Certificate::new((0, 0), vec![board.clone(), board.clone()]).kind(), PatternKind::Oscillator);
+        assert_eq!(Certificate::new((1, 0), vec![board]).kind(), PatternKind::Spaceship);
+    }
+}
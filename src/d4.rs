@@ -0,0 +1,116 @@
+use num_traits::{One, Zero};
+use std::ops::Neg;
+
+/// A symmetry of the square, i.e., an element of the [dihedral group](https://en.wikipedia.org/wiki/Dihedral_group) of order 8.
+///
+/// Each symmetry is a linear transformation around the origin.
+/// The x-axis points right and the y-axis points down, so rotations are clockwise as displayed.
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Position, D4};
+/// let m = D4::Rotate90.to_matrix();
+/// assert_eq!(Position(1, 0).transform(m, Position(0, 0)), Position(0, 1));
+/// assert_eq!(D4::ALL.len(), 8);
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum D4 {
+    /// The identity transformation.
+    Identity,
+    /// The rotation by 90 degrees clockwise.
+    Rotate90,
+    /// The rotation by 180 degrees.
+    Rotate180,
+    /// The rotation by 270 degrees clockwise, i.e., 90 degrees counterclockwise.
+    Rotate270,
+    /// The reflection across the y-axis, i.e., the horizontal flip.
+    FlipX,
+    /// The reflection across the x-axis, i.e., the vertical flip.
+    FlipY,
+    /// The reflection across the diagonal `x == y`, i.e., the transposition.
+    FlipDiagonal,
+    /// The reflection across the anti-diagonal `x == -y`.
+    FlipAntiDiagonal,
+}
+
+// Inherent methods
+
+impl D4 {
+    /// All symmetries of the square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::D4;
+    /// assert_eq!(D4::ALL[0], D4::Identity);
+    /// ```
+    ///
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipX,
+        Self::FlipY,
+        Self::FlipDiagonal,
+        Self::FlipAntiDiagonal,
+    ];
+
+    /// Returns the matrix of the symmetry in row-major order, suitable for [`Position::transform()`].
+    ///
+    /// [`Position::transform()`]: crate::Position::transform
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::D4;
+    /// assert_eq!(D4::Identity.to_matrix::<i16>(), [[1, 0], [0, 1]]);
+    /// assert_eq!(D4::FlipX.to_matrix::<i16>(), [[-1, 0], [0, 1]]);
+    /// ```
+    ///
+    pub fn to_matrix<T>(&self) -> [[T; 2]; 2]
+    where
+        T: Zero + One + Neg<Output = T>,
+    {
+        let (o, i) = (T::zero, T::one);
+        let n = || -T::one();
+        match self {
+            Self::Identity => [[i(), o()], [o(), i()]],
+            Self::Rotate90 => [[o(), n()], [i(), o()]],
+            Self::Rotate180 => [[n(), o()], [o(), n()]],
+            Self::Rotate270 => [[o(), i()], [n(), o()]],
+            Self::FlipX => [[n(), o()], [o(), i()]],
+            Self::FlipY => [[i(), o()], [o(), n()]],
+            Self::FlipDiagonal => [[o(), i()], [i(), o()]],
+            Self::FlipAntiDiagonal => [[o(), n()], [n(), o()]],
+        }
+    }
+}
+
+// Unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+    #[test]
+    fn rotate90_four_times() {
+        let m = D4::Rotate90.to_matrix::<i16>();
+        let origin = Position(0, 0);
+        let pos = Position(2, 1);
+        let result = (0..4).fold(pos, |curr, _| curr.transform(m, origin));
+        assert_eq!(result, pos);
+    }
+    #[test]
+    fn flips_are_involutions() {
+        let origin = Position(0, 0);
+        let pos = Position(2, 1);
+        for sym in [D4::FlipX, D4::FlipY, D4::FlipDiagonal, D4::FlipAntiDiagonal] {
+            let m = sym.to_matrix::<i16>();
+            assert_ne!(pos.transform(m, origin), pos);
+            assert_eq!(pos.transform(m, origin).transform(m, origin), pos);
+        }
+    }
+}
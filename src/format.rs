@@ -1,11 +1,16 @@
 //! Provides several functionalities related to file formats.
 
-use anyhow::{bail, Context as _, Result};
+use anyhow::{bail, ensure, Context as _, Result};
+use num_traits::CheckedAdd;
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
-use std::fs::File;
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::io;
 use std::path::Path;
 
-use crate::{Position, Rule};
+use crate::{Board, Position, Rule};
 
 mod plaintext;
 pub use plaintext::{Plaintext, PlaintextBuilder};
@@ -13,6 +18,24 @@ pub use plaintext::{Plaintext, PlaintextBuilder};
 mod rle;
 pub use rle::{Rle, RleBuilder};
 
+mod life106;
+pub use life106::Life106;
+
+mod coordlist;
+pub use coordlist::CoordList;
+
+mod summary;
+pub use summary::PatternSummary;
+
+mod error;
+pub use error::{FormatError, FormatErrorKind};
+
+mod kind;
+pub use kind::FormatKind;
+
+// The file extensions supported by open(), corresponding to format_for_extension()
+const SUPPORTED_EXTENSIONS: [&str; 4] = ["cells", "rle", "coords", "life"];
+
 /// Provides several methods for Conway's Game of Life pattern file formats.
 ///
 /// # Examples
@@ -72,6 +95,39 @@ pub trait Format: fmt::Display {
     /// ```
     ///
     fn live_cells(&self) -> Box<dyn Iterator<Item = Position<usize>> + '_>;
+
+    /// Returns the summary of the pattern, which consists of the population, the bounding box, the rule, and the name and comments if available.
+    ///
+    /// The default implementation aggregates [`rule()`] and [`live_cells()`], and provides no name and comments.
+    ///
+    /// [`rule()`]: #tymethod.rule
+    /// [`live_cells()`]: #tymethod.live_cells
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Format, Rule};
+    /// use life_backend::format::Rle;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     #N T-tetromino\n\
+    ///     x = 3, y = 2, rule = B3/S23\n\
+    ///     3o$bo!\n\
+    /// ";
+    /// let handler: Box<dyn Format> = Box::new(pattern.parse::<Rle>()?);
+    /// let summary = handler.summary();
+    /// assert_eq!(summary.population(), 4);
+    /// assert_eq!(summary.bounding_box().x(), &(0..=2));
+    /// assert_eq!(summary.bounding_box().y(), &(0..=1));
+    /// assert_eq!(summary.rule(), &Rule::conways_life());
+    /// assert_eq!(summary.name(), Some("T-tetromino"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    fn summary(&self) -> PatternSummary {
+        PatternSummary::from_format(self)
+    }
 }
 
 /// Attempts to open a file with the file format handler specified by the file extension.
@@ -112,13 +168,289 @@ where
         .extension()
         .with_context(|| format!("\"{}\" has no extension", path_for_display.display()))?
         .to_owned();
+    let Some(kind) = ext.to_str().and_then(format_for_extension) else {
+        bail!("\"{}\" has unknown extension", path_for_display.display());
+    };
+    open_as(path, kind)
+}
+
+/// Attempts to open a file with the specified file format handler, regardless of the file extension.
+///
+/// This is useful for files with extensions not supported by [`open()`], e.g., a `.txt` file in the coordinate-list format.
+///
+/// [`open()`]: #fn.open
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format::{self, FormatKind};
+/// use life_backend::Rule;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let path = "patterns/glider.coords";
+/// let handler = format::open_as(path, FormatKind::CoordList)?;
+/// assert_eq!(handler.rule(), Rule::conways_life());
+/// assert_eq!(handler.live_cells().count(), 5);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn open_as<P>(path: P, kind: FormatKind) -> Result<Box<dyn Format>>
+where
+    P: AsRef<Path>,
+{
+    let path_for_display = path.as_ref().to_owned();
     let file = File::open(path).with_context(|| format!("Failed to open \"{}\"", path_for_display.display()))?;
-    let result: Box<dyn Format> = if ext.as_os_str() == "cells" {
-        Box::new(Plaintext::new(file)?)
-    } else if ext.as_os_str() == "rle" {
-        Box::new(Rle::new(file)?)
+    let result: Box<dyn Format> = match kind {
+        FormatKind::Plaintext => Box::new(Plaintext::new(file)?),
+        FormatKind::Rle => Box::new(Rle::new(file)?),
+        FormatKind::CoordList => Box::new(CoordList::new(file)?),
+        FormatKind::Compact => parse_str(&io::read_to_string(file)?)?,
+    };
+    Ok(result)
+}
+
+/// Returns the file extensions supported by [`open()`], without the leading dot.
+///
+/// [`open()`]: #fn.open
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format;
+/// assert!(format::supported_extensions().contains(&"rle"));
+/// ```
+///
+pub fn supported_extensions() -> &'static [&'static str] {
+    &SUPPORTED_EXTENSIONS
+}
+
+/// Returns the kind of the file format corresponding to the specified file extension, without the leading dot.
+///
+/// Returns `None` if the extension is not supported by [`open()`].
+///
+/// [`open()`]: #fn.open
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format::{self, FormatKind};
+/// assert_eq!(format::format_for_extension("cells"), Some(FormatKind::Plaintext));
+/// assert_eq!(format::format_for_extension("txt"), None);
+/// ```
+///
+pub fn format_for_extension(ext: &str) -> Option<FormatKind> {
+    match ext {
+        "cells" => Some(FormatKind::Plaintext),
+        "rle" => Some(FormatKind::Rle),
+        "coords" => Some(FormatKind::CoordList),
+        "life" => Some(FormatKind::Compact),
+        _ => None,
+    }
+}
+
+/// Saves the specified live cells and the rule into the file, choosing the most compact format for the pattern.
+///
+/// The path must have the extension `.life`.
+/// The pattern is converted into RLE, Plaintext and Life 1.06 formats, and the shortest one is written into the file.
+/// Since Plaintext and Life 1.06 formats cannot hold a rule, they are chosen only if the rule is [Conway's Life](Rule::conways_life).
+/// Generally, sparse patterns favor Life 1.06 format and dense patterns favor RLE format.
+/// The written file includes a comment line noting the chosen format.
+/// The file can be read back by [`open()`].
+///
+/// [`open()`]: #fn.open
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format;
+/// use life_backend::{Position, Rule};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let path = std::env::temp_dir().join(format!("life_backend_doctest_save_compact_{}.life", std::process::id()));
+/// let cells = [Position(0, 0), Position(1000, 0), Position(0, 1000)]; // Very sparse pattern
+/// format::save_compact(&path, &cells, Rule::conways_life())?;
+/// let contents = std::fs::read_to_string(&path)?;
+/// assert!(contents.starts_with("#Life 1.06"));
+/// let handler = format::open(&path)?;
+/// assert!(handler.live_cells().eq(cells.iter().copied()));
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn save_compact<P>(path: P, cells: &[Position<usize>], rule: Rule) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    ensure!(
+        path.extension().map_or(false, |ext| ext == "life"),
+        "\"{}\" does not have the extension \".life\"",
+        path.display()
+    );
+    let contents = to_compact_string(cells, rule)?;
+    fs::write(path, contents).with_context(|| format!("Failed to write \"{}\"", path.display()))?;
+    Ok(())
+}
+
+// Converts the specified live cells and the rule into the shortest string among RLE, Plaintext and Life 1.06 formats, used by save_compact()
+fn to_compact_string(cells: &[Position<usize>], rule: Rule) -> Result<String> {
+    fn description(format_name: &str) -> String {
+        format!("Saved in {format_name} format, the most compact one for this pattern")
+    }
+    let is_conways_life = rule == Rule::conways_life();
+    let mut candidates = vec![cells
+        .iter()
+        .collect::<RleBuilder>()
+        .comment(&description("RLE"))
+        .rule(rule)
+        .build()?
+        .to_string()];
+    if is_conways_life {
+        candidates.push(
+            cells
+                .iter()
+                .collect::<PlaintextBuilder>()
+                .comment(&description("Plaintext"))
+                .build()?
+                .to_string(),
+        );
+        candidates.push(Life106::to_string_with_description(cells, &description("Life 1.06"))?);
+    }
+    let result = candidates.into_iter().min_by_key(String::len).unwrap(); // this unwrap never panic because candidates always includes the RLE string
+    Ok(result)
+}
+
+/// Creates a board from live cells of the specified format handler.
+///
+/// Returns an error if the position of a live cell cannot be converted into `Position<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format;
+/// use life_backend::{Board, Position};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let handler = format::open("patterns/glider.rle")?;
+/// let board: Board<i16> = format::to_board(handler.as_ref())?;
+/// assert_eq!(board.iter().count(), 5);
+/// assert_eq!(board.contains(&Position(1, 0)), true);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_board<T>(handler: &dyn Format) -> Result<Board<T>>
+where
+    T: Eq + Hash + TryFrom<usize>,
+    T::Error: Error + Send + Sync + 'static,
+{
+    let board = handler.live_cells().map(Position::try_from).collect::<Result<Board<_>, _>>()?;
+    Ok(board)
+}
+
+/// Loads the pattern files at the specified paths, translates each pattern by the corresponding offset, and unions them into a board.
+///
+/// Each file is loaded in the same way as [`open()`], and `paths` and `offsets` must have the same length.
+/// Returns an error if loading a file fails, or if the position of a live cell cannot be converted into `Position<T>` after the translation.
+///
+/// [`open()`]: #fn.open
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format;
+/// use life_backend::Position;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let board = format::load_all_into(&["patterns/glider.rle", "patterns/rpentomino.cells"], &[Position(0, 0), Position(10, 0)])?;
+/// assert_eq!(board.iter().count(), 5 + 5);
+/// assert_eq!(board.contains(&Position(11, 0)), true);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn load_all_into<T, P>(paths: &[P], offsets: &[Position<T>]) -> Result<Board<T>>
+where
+    T: Eq + Hash + Copy + CheckedAdd + TryFrom<usize>,
+    T::Error: Error + Send + Sync + 'static,
+    P: AsRef<Path>,
+{
+    ensure!(
+        paths.len() == offsets.len(),
+        "the number of paths ({}) differs from the number of offsets ({})",
+        paths.len(),
+        offsets.len()
+    );
+    let mut board = Board::new();
+    for (path, &Position(offset_x, offset_y)) in paths.iter().zip(offsets.iter()) {
+        let handler = open(path)?;
+        for pos in handler.live_cells() {
+            let Position(x, y) = Position::<T>::try_from(pos)?;
+            let translated = x
+                .checked_add(&offset_x)
+                .zip(y.checked_add(&offset_y))
+                .with_context(|| format!("translated position of {:?} overflows in \"{}\"", pos, path.as_ref().display()))?;
+            board.insert(Position(translated.0, translated.1));
+        }
+    }
+    Ok(board)
+}
+
+/// Compares live cells of the specified format handlers, and returns the cells only in `a` and the cells only in `b`.
+///
+/// Before comparison, the live cells of each handler are aligned to the origin, i.e., translated so that the minimum x- and y-coordinate values are zero.
+/// Each returned vector is sorted in row-major order, and the positions in it are the aligned ones.
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format::{self, Rle};
+/// use life_backend::Position;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = "x = 3, y = 1\n3o!\n".parse::<Rle>()?;
+/// let b = "x = 4, y = 2\n$b2o!\n".parse::<Rle>()?;
+/// let (only_a, only_b) = format::diff(&a, &b);
+/// assert_eq!(only_a, vec![Position(2, 0)]);
+/// assert!(only_b.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn diff(a: &dyn Format, b: &dyn Format) -> (Vec<Position<usize>>, Vec<Position<usize>>) {
+    fn aligned_board(handler: &dyn Format) -> Board<usize> {
+        let (min_x, min_y) = handler
+            .live_cells()
+            .fold((usize::MAX, usize::MAX), |(min_x, min_y), Position(x, y)| (min_x.min(x), min_y.min(y)));
+        handler.live_cells().map(|Position(x, y)| Position(x - min_x, y - min_y)).collect()
+    }
+    fn difference(lhs: &Board<usize>, rhs: &Board<usize>) -> Vec<Position<usize>> {
+        lhs.difference_iter(rhs).collect::<Board<_>>().into_sorted_vec()
+    }
+    let (a, b) = (aligned_board(a), aligned_board(b));
+    (difference(&a, &b), difference(&b, &a))
+}
+
+// Parses the specified string with the format handler detected from its content.
+// The string is treated as Life 1.06 if the first non-empty line is the header line of Life 1.06 ("#Life 1.06"),
+// as Plaintext if the first non-empty line is a comment line of Plaintext ("!...") or a content line of Plaintext ("." and "O" only),
+// and as RLE otherwise.
+// Since Life106 does not implement Format, a Life 1.06 pattern is returned as CoordList, and its coordinates must not be negative.
+pub(crate) fn parse_str(s: &str) -> Result<Box<dyn Format>> {
+    let first_line = s.lines().find(|line| !line.trim().is_empty());
+    if first_line.map_or(false, |line| line.trim_end() == "#Life 1.06") {
+        let contents = s
+            .parse::<Life106>()?
+            .live_cells()
+            .map(|pos| Position::<usize>::try_from(pos).context("Negative coordinate value found in the Life 1.06 pattern"))
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(Box::new(CoordList::from_positions(contents)));
+    }
+    let is_plaintext = match first_line {
+        Some(line) => line.starts_with('!') || line.chars().all(|c| c == '.' || c == 'O'),
+        None => true,
+    };
+    let result: Box<dyn Format> = if is_plaintext {
+        Box::new(s.parse::<Plaintext>()?)
     } else {
-        bail!("\"{}\" has unknown extension", path_for_display.display());
+        Box::new(s.parse::<Rle>()?)
     };
     Ok(result)
 }
@@ -138,4 +470,172 @@ mod tests {
         let result = open(path);
         assert!(result.is_err());
     }
+    #[test]
+    fn supported_extensions_rle_and_cells() {
+        let result = supported_extensions();
+        assert!(result.contains(&"rle"));
+        assert!(result.contains(&"cells"));
+        assert!(result.iter().all(|ext| format_for_extension(ext).is_some()));
+    }
+    #[test]
+    fn open_coords_round_trip() -> Result<()> {
+        let path = "patterns/glider.coords";
+        let handler = open(path)?;
+        let result: Board<i16> = to_board(handler.as_ref())?;
+        let expected: Board<i16> = to_board(open("patterns/glider.rle")?.as_ref())?;
+        assert_eq!(result, expected);
+        assert_eq!(handler.to_string(), fs::read_to_string(path)?);
+        Ok(())
+    }
+    #[test]
+    fn to_board_glider() -> Result<()> {
+        let handler = open("patterns/glider.rle")?;
+        let result: Board<i16> = to_board(handler.as_ref())?;
+        let expected: Board<_> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        assert_eq!(result, expected);
+        Ok(())
+    }
+    #[test]
+    fn to_board_overflow() -> Result<()> {
+        let handler = parse_str("x = 200, y = 1\n199bo!")?;
+        let result = to_board::<i8>(handler.as_ref());
+        assert!(result.is_err());
+        Ok(())
+    }
+    #[test]
+    fn load_all_into_two_gliders() -> Result<()> {
+        let path = "patterns/glider.rle";
+        let result: Board<i16> = load_all_into(&[path, path], &[Position(0, 0), Position(-10, 5)])?;
+        let glider: Board<i16> = to_board(open(path)?.as_ref())?;
+        let expected: Board<_> = glider
+            .iter()
+            .chain(glider.map_positions(|Position(x, y)| Position(x - 10, y + 5)).iter())
+            .collect();
+        assert_eq!(result, expected);
+        assert_eq!(result.iter().count(), 10);
+        Ok(())
+    }
+    #[test]
+    fn load_all_into_mismatched_lengths() {
+        let result = load_all_into::<i16, _>(&["patterns/glider.rle"], &[]);
+        assert!(result.is_err());
+    }
+    #[test]
+    fn load_all_into_overflow() {
+        let result = load_all_into::<i8, _>(&["patterns/glider.rle"], &[Position(126, 0)]);
+        assert!(result.is_err());
+    }
+    #[test]
+    fn diff_single_cell() -> Result<()> {
+        let a = parse_str("x = 5, y = 4\nbo2bo$o$o3bo$4o!")?; // Lightweight spaceship
+        let b = parse_str("x = 6, y = 5\n$2bo2bo$bo$bo3bo$b3o!")?;
+        let (only_a, only_b) = diff(a.as_ref(), b.as_ref());
+        assert_eq!(only_a, vec![Position(3, 3)]);
+        assert!(only_b.is_empty());
+        let (only_b, only_a) = diff(b.as_ref(), a.as_ref());
+        assert!(only_b.is_empty());
+        assert_eq!(only_a, vec![Position(3, 3)]);
+        Ok(())
+    }
+    #[test]
+    fn diff_identical() -> Result<()> {
+        let a = open("patterns/glider.rle")?;
+        let b = parse_str(".O.\n..O\nOOO\n")?;
+        assert_eq!(diff(a.as_ref(), b.as_ref()), (Vec::new(), Vec::new()));
+        Ok(())
+    }
+    #[test]
+    fn to_compact_string_dense() -> Result<()> {
+        let cells: Vec<_> = (0..8).flat_map(|y| (0..8).map(move |x| Position(x, y))).collect();
+        let result = to_compact_string(&cells, Rule::conways_life())?;
+        assert!(result.starts_with("#C"));
+        Ok(())
+    }
+    #[test]
+    fn to_compact_string_sparse() -> Result<()> {
+        let cells = [Position(0, 0), Position(1000, 20), Position(30, 2000), Position(3000, 3000)];
+        let result = to_compact_string(&cells, Rule::conways_life())?;
+        assert!(result.starts_with("#Life 1.06\n#D Saved in Life 1.06 format"));
+        let parsed = result.parse::<Life106>()?;
+        assert!(parsed.live_cells().eq(cells.iter().map(|&pos| Position::<i64>::try_from(pos).unwrap())));
+        Ok(())
+    }
+    #[test]
+    fn to_compact_string_non_conways_rule() -> Result<()> {
+        let cells = [Position(0, 0), Position(1000, 20), Position(30, 2000), Position(3000, 3000)];
+        let rule: Rule = "B36/S23".parse()?;
+        let result = to_compact_string(&cells, rule.clone())?;
+        assert_eq!(result.parse::<Rle>()?.rule(), &rule);
+        Ok(())
+    }
+    #[test]
+    fn save_compact_sparse() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("life_backend_test_save_compact_sparse_{}.life", std::process::id()));
+        let cells = [Position(0, 0), Position(1000, 20), Position(30, 2000), Position(3000, 3000)];
+        save_compact(&path, &cells, Rule::conways_life())?;
+        let result = fs::read_to_string(&path)?;
+        fs::remove_file(&path)?;
+        assert!(result.starts_with("#Life 1.06"));
+        Ok(())
+    }
+    #[test]
+    fn save_compact_reload() -> Result<()> {
+        let sparse = [Position(0, 0), Position(1000, 20), Position(30, 2000), Position(3000, 3000)];
+        let dense: Vec<_> = (0..8).flat_map(|y| (0..8).map(move |x| Position(x, y))).collect();
+        let glider = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)];
+        let highlife: Rule = "B36/S23".parse()?;
+        let cases = [
+            ("sparse", &sparse[..], Rule::conways_life()),
+            ("dense", &dense[..], Rule::conways_life()),
+            ("glider", &glider[..], Rule::conways_life()),
+            ("highlife", &glider[..], highlife),
+        ];
+        for (name, cells, rule) in cases {
+            let path = std::env::temp_dir().join(format!("life_backend_test_save_compact_reload_{}_{}.life", name, std::process::id()));
+            save_compact(&path, cells, rule.clone())?;
+            let result = open(&path);
+            fs::remove_file(&path)?;
+            let result = result?;
+            assert_eq!(result.rule(), rule);
+            let result: Board<usize> = result.live_cells().collect();
+            let expected: Board<usize> = cells.iter().collect();
+            assert_eq!(result, expected);
+        }
+        Ok(())
+    }
+    #[test]
+    fn save_compact_wrong_extension() {
+        let path = std::env::temp_dir().join(format!("life_backend_test_save_compact_wrong_extension_{}.rle", std::process::id()));
+        let result = save_compact(&path, &[Position(0, 0)], Rule::conways_life());
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+    #[test]
+    fn parse_str_plaintext() -> Result<()> {
+        let pattern = "!Name: T-tetromino\nOOO\n.O.\n";
+        let result = parse_str(pattern)?;
+        assert!(result.live_cells().eq([Position(0, 0), Position(1, 0), Position(2, 0), Position(1, 1)]));
+        Ok(())
+    }
+    #[test]
+    fn parse_str_life106() -> Result<()> {
+        let pattern = "#Life 1.06\n2 2\n1 0\n0 2\n2 1\n1 2\n1 0\n";
+        let result = parse_str(pattern)?;
+        assert_eq!(result.rule(), Rule::conways_life());
+        assert!(result
+            .live_cells()
+            .eq([Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]));
+        assert!(parse_str("#Life 1.06\n-1 0\n").is_err());
+        Ok(())
+    }
+    #[test]
+    fn parse_str_rle() -> Result<()> {
+        let pattern = "#N T-tetromino\nx = 3, y = 2, rule = B36/S23\n3o$bo!\n";
+        let result = parse_str(pattern)?;
+        assert_eq!(result.rule(), "B36/S23".parse()?);
+        assert!(result.live_cells().eq([Position(0, 0), Position(1, 0), Position(2, 0), Position(1, 1)]));
+        Ok(())
+    }
 }
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::io::{BufRead as _, BufReader, Read};
+use std::str::FromStr;
+
+use crate::format::{FormatError, FormatErrorKind};
+use crate::{Format, Position, Rule};
+
+/// A representation for a simple coordinate-list text format.
+///
+/// Each line holds the x- and y-coordinate values of a live cell, separated by whitespace or a comma, e.g., `"1 2"` or `"1,2"`.
+/// This format is similar to [`Life106`] without the header line, so it is easy to emit from shell scripts.
+/// Blank lines and lines starting with `#` are ignored.
+/// The live cells are held in ascending order (row-major), and duplicate positions are merged into one.
+///
+/// Since this format has no way to specify a rule, the rule is always [Conway's Life](Rule::conways_life).
+/// The coordinate values must not be negative.
+///
+/// [`Life106`]: crate::format::Life106
+///
+/// # Examples
+///
+/// Parses the given string in the coordinate-list format:
+///
+/// ```
+/// use life_backend::format::CoordList;
+/// use life_backend::{Format, Position, Rule};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pattern = "\
+///     1 0\n\
+///     2,1\n\
+///     0 2\n\
+/// ";
+/// let handler = pattern.parse::<CoordList>()?;
+/// assert_eq!(handler.rule(), Rule::conways_life());
+/// assert!(handler.live_cells().eq([Position(1, 0), Position(2, 1), Position(0, 2)]));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct CoordList {
+    contents: Vec<Position<usize>>,
+}
+
+// Inherent methods
+
+impl CoordList {
+    /// Creates from the specified implementor of [`Read`], such as [`File`] or `&[u8]`.
+    ///
+    /// [`Read`]: std::io::Read
+    /// [`File`]: std::fs::File
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::CoordList;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     1 0\n\
+    ///     2,1\n\
+    ///     0 2\n\
+    /// ";
+    /// let parser = CoordList::new(pattern.as_bytes())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn new<R>(read: R) -> Result<Self, FormatError>
+    where
+        R: Read,
+    {
+        let mut contents = Vec::new();
+        for (i, line) in BufReader::new(read).lines().enumerate() {
+            if let Some(position) = Self::parse_line(&line?).map_err(|e| FormatError::at_line(e, i + 1))? {
+                contents.push(position);
+            }
+        }
+        Ok(Self::from_positions(contents))
+    }
+
+    /// Creates a non-owning iterator over the series of live cell positions in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::CoordList;
+    /// use life_backend::Position;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     1 0\n\
+    ///     2,1\n\
+    ///     0 2\n\
+    /// ";
+    /// let parser = CoordList::new(pattern.as_bytes())?;
+    /// assert!(parser.live_cells().eq([Position(1, 0), Position(2, 1), Position(0, 2)]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn live_cells(&self) -> impl Iterator<Item = Position<usize>> + '_ {
+        self.contents.iter().copied()
+    }
+
+    // Creates from the specified live cell positions, sorted in ascending order and deduplicated.
+    // This is also used for reading Life 1.06 patterns via parse_str()
+    pub(super) fn from_positions(mut contents: Vec<Position<usize>>) -> Self {
+        contents.sort_unstable_by_key(|&Position(x, y)| (y, x));
+        contents.dedup();
+        Self { contents }
+    }
+
+    // Parses the line, returns None if the line is blank or a comment line
+    fn parse_line(line: &str) -> Result<Option<Position<usize>>, FormatErrorKind> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        let fields: Vec<_> = line.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty()).collect();
+        if fields.len() != 2 {
+            return Err(FormatErrorKind::InvalidContent);
+        }
+        let x = fields[0].parse().map_err(|_| FormatErrorKind::InvalidContent)?;
+        let y = fields[1].parse().map_err(|_| FormatErrorKind::InvalidContent)?;
+        Ok(Some(Position(x, y)))
+    }
+}
+
+// Trait implementations
+
+impl Format for CoordList {
+    fn rule(&self) -> Rule {
+        Rule::conways_life()
+    }
+    fn live_cells(&self) -> Box<dyn Iterator<Item = Position<usize>> + '_> {
+        Box::new(self.live_cells())
+    }
+}
+
+impl fmt::Display for CoordList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for Position(x, y) in self.live_cells() {
+            writeln!(f, "{x} {y}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CoordList {
+    type Err = FormatError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.as_bytes())
+    }
+}
+
+// Unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    #[test]
+    fn new_separators_and_comments() -> Result<()> {
+        let pattern = "# Glider\n1 0\n2,1\n\n0, 2\n  1\t2\n2 2\n";
+        let target: CoordList = pattern.parse()?;
+        assert!(target
+            .live_cells()
+            .eq([Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]));
+        Ok(())
+    }
+    #[test]
+    fn new_unsorted_and_duplicated() -> Result<()> {
+        let pattern = "2 2\n0 2\n1 0\n2,2\n2 1\n1 0\n1 2\n";
+        let target: CoordList = pattern.parse()?;
+        assert!(target
+            .live_cells()
+            .eq([Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]));
+        assert_eq!(Format::summary(&target).population(), 5);
+        Ok(())
+    }
+    #[test]
+    fn new_wrong_content() {
+        assert!("0 0 0\n".parse::<CoordList>().is_err());
+        assert!("0\n".parse::<CoordList>().is_err());
+        assert!("-1 0\n".parse::<CoordList>().is_err());
+        assert!("a,b\n".parse::<CoordList>().is_err());
+    }
+    #[test]
+    fn new_wrong_content_line_number() {
+        let target = "# Glider\n1 0\n2,x\n".parse::<CoordList>();
+        assert!(matches!(&target, Err(e) if matches!(e.kind(), FormatErrorKind::InvalidContent) && e.line() == Some(3)));
+    }
+    #[test]
+    fn display_round_trip() -> Result<()> {
+        let pattern = "1 0\n2 1\n0 2\n1 2\n2 2\n";
+        let target: CoordList = pattern.parse()?;
+        assert_eq!(target.to_string(), pattern);
+        let result: CoordList = target.to_string().parse()?;
+        assert!(result.live_cells().eq(target.live_cells()));
+        Ok(())
+    }
+}
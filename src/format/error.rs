@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// An error which can be returned when parsing a pattern file, such as [`Rle`] or [`Plaintext`].
+///
+/// The reason of the error is returned by [`kind()`], and the line where the error occurred is returned by [`line()`].
+///
+/// [`Rle`]: crate::format::Rle
+/// [`Plaintext`]: crate::format::Plaintext
+/// [`kind()`]: #method.kind
+/// [`line()`]: #method.line
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format::{FormatErrorKind, Rle};
+/// let pattern = "\
+///     x = 2, y = 1\n\
+///     3o!\n\
+/// ";
+/// let err = Rle::new(pattern.as_bytes()).unwrap_err();
+/// assert!(matches!(err.kind(), FormatErrorKind::ExceedsWidth));
+/// assert_eq!(err.line(), Some(2));
+/// assert_eq!(err.to_string(), "line 2: The pattern exceeds specified width");
+/// ```
+///
+#[derive(Debug)]
+pub struct FormatError {
+    kind: FormatErrorKind,
+    line: Option<usize>,
+}
+
+/// A list of the reasons of [`FormatError`].
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format::{FormatErrorKind, Rle};
+/// let pattern = "\
+///     #N T-tetromino\n\
+/// ";
+/// let err = Rle::new(pattern.as_bytes()).unwrap_err();
+/// assert!(matches!(err.kind(), FormatErrorKind::MissingHeader));
+/// ```
+///
+#[derive(Debug)]
+pub enum FormatErrorKind {
+    /// An I/O error occurred while reading the pattern.
+    Io(io::Error),
+    /// The header line is not found in the pattern.
+    MissingHeader,
+    /// The header line is in wrong format. The detail of the error is held.
+    InvalidHeader(String),
+    /// The rule in the header line is invalid.
+    InvalidRule,
+    /// The terminal symbol is not found in the pattern.
+    UnterminatedPattern,
+    /// The pattern exceeds the width written in the header line.
+    ExceedsWidth,
+    /// The pattern exceeds the height written in the header line.
+    ExceedsHeight,
+    /// The content of the pattern is in wrong format.
+    InvalidContent,
+    /// An invalid character is found in the pattern.
+    InvalidCharacter,
+}
+
+// Inherent methods
+
+impl FormatError {
+    // Creates an error of the specified kind occurred at the specified line (starting from 1)
+    pub(crate) fn at_line(kind: FormatErrorKind, line: usize) -> Self {
+        Self { kind, line: Some(line) }
+    }
+
+    /// Returns the reason of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::{FormatErrorKind, Plaintext};
+    /// let pattern = "\
+    ///     !Name: T-tetromino\n\
+    ///     OOO\n\
+    ///     .X.\n\
+    /// ";
+    /// let err = Plaintext::new(pattern.as_bytes()).unwrap_err();
+    /// assert!(matches!(err.kind(), FormatErrorKind::InvalidCharacter));
+    /// ```
+    ///
+    #[inline]
+    pub const fn kind(&self) -> &FormatErrorKind {
+        &self.kind
+    }
+
+    /// Returns the line number (starting from 1) where the error occurred, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Plaintext;
+    /// let pattern = "\
+    ///     !Name: T-tetromino\n\
+    ///     OOO\n\
+    ///     .X.\n\
+    /// ";
+    /// let err = Plaintext::new(pattern.as_bytes()).unwrap_err();
+    /// assert_eq!(err.line(), Some(3));
+    /// ```
+    ///
+    #[inline]
+    pub const fn line(&self) -> Option<usize> {
+        self.line
+    }
+}
+
+// Trait implementations
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            FormatErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl From<FormatErrorKind> for FormatError {
+    #[inline]
+    fn from(kind: FormatErrorKind) -> Self {
+        Self { kind, line: None }
+    }
+}
+
+impl From<io::Error> for FormatError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        FormatErrorKind::Io(e).into()
+    }
+}
+
+impl fmt::Display for FormatErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(_) => f.write_str("Failed to read the pattern"),
+            Self::MissingHeader => f.write_str("Header line not found in the pattern"),
+            Self::InvalidHeader(detail) => f.write_str(detail),
+            Self::InvalidRule => f.write_str("Invalid rule value"),
+            Self::UnterminatedPattern => f.write_str("The terminal symbol not found"),
+            Self::ExceedsWidth => f.write_str("The pattern exceeds specified width"),
+            Self::ExceedsHeight => f.write_str("The pattern exceeds specified height"),
+            Self::InvalidContent => f.write_str("The pattern is in wrong format"),
+            Self::InvalidCharacter => f.write_str("Invalid character found in the pattern"),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+/// A kind of file formats supported by [`open()`].
+///
+/// [`open()`]: crate::format::open
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::format::{self, FormatKind};
+/// assert_eq!(format::format_for_extension("rle"), Some(FormatKind::Rle));
+/// assert_eq!(format::format_for_extension("cells"), Some(FormatKind::Plaintext));
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum FormatKind {
+    /// Plaintext format, with the extension `cells`.
+    Plaintext,
+    /// RLE format, with the extension `rle`.
+    Rle,
+    /// The coordinate-list format, with the extension `coords`.
+    CoordList,
+    /// The format written by [`save_compact()`], with the extension `life`.
+    /// The actual format (RLE, Plaintext or Life 1.06) is detected from the content.
+    ///
+    /// [`save_compact()`]: crate::format::save_compact
+    Compact,
+}
@@ -0,0 +1,232 @@
+use anyhow::{anyhow, ensure, Context as _, Result};
+use std::fmt;
+use std::io::{BufRead as _, BufReader, Read};
+use std::str::FromStr;
+
+use crate::Position;
+
+/// A representation for Life 1.06 file format.
+///
+/// The detail of this format is described in:
+///
+/// - [Life 1.06 - LifeWiki](https://conwaylife.com/wiki/Life_1.06)
+///
+/// Since the coordinates in this format may be negative, live cell positions are represented as `Position<i64>`.
+/// For the same reason, this type does not implement [`Format`], whose live cell positions are `Position<usize>`.
+///
+/// [`Format`]: crate::Format
+///
+/// # Examples
+///
+/// Parses the given string in Life 1.06 format:
+///
+/// ```
+/// use life_backend::format::Life106;
+/// use life_backend::Position;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pattern = "\
+///     #Life 1.06\n\
+///     0 -1\n\
+///     -1 0\n\
+///     0 0\n\
+/// ";
+/// let parser = pattern.parse::<Life106>()?;
+/// assert!(parser.live_cells().eq([Position(0, -1), Position(-1, 0), Position(0, 0)]));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct Life106 {
+    contents: Vec<Position<i64>>,
+}
+
+// Inherent methods
+
+impl Life106 {
+    /// Creates from the specified implementor of [`Read`], such as [`File`] or `&[u8]`.
+    ///
+    /// This method reads all live cells into memory.
+    /// To process a very large file without building the whole structure, use [`stream()`] instead.
+    ///
+    /// [`Read`]: std::io::Read
+    /// [`File`]: std::fs::File
+    /// [`stream()`]: #method.stream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Life106;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     #Life 1.06\n\
+    ///     0 -1\n\
+    ///     -1 0\n\
+    ///     0 0\n\
+    /// ";
+    /// let parser = Life106::new(pattern.as_bytes())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn new<R>(read: R) -> Result<Self>
+    where
+        R: Read,
+    {
+        let contents = Self::stream(read).collect::<Result<Vec<_>>>()?;
+        Ok(Self { contents })
+    }
+
+    /// Creates an owning iterator that lazily parses live cell positions from the specified implementor of [`Read`], such as [`File`] or `&[u8]`.
+    ///
+    /// Each item is the position of a live cell in the order written in the pattern, or an error if the corresponding line cannot be parsed.
+    /// Unlike [`new()`], the whole pattern is never materialized in memory, so it is suitable for very large files.
+    ///
+    /// [`Read`]: std::io::Read
+    /// [`File`]: std::fs::File
+    /// [`new()`]: #method.new
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs::File;
+    /// use life_backend::format::Life106;
+    /// use life_backend::{Board, Position};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = File::open("patterns/glider.lif")?;
+    /// let board = Life106::stream(file).collect::<Result<Board<_>, _>>()?;
+    /// assert_eq!(board.iter().count(), 5);
+    /// assert_eq!(board.contains(&Position(1, 0)), true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream<R>(read: R) -> impl Iterator<Item = Result<Position<i64>>>
+    where
+        R: Read,
+    {
+        BufReader::new(read).lines().enumerate().filter_map(|(index, line)| {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            if index == 0 {
+                if line.trim_end() == Self::HEADER {
+                    None
+                } else {
+                    Some(Err(anyhow!("Header line not found in the pattern")))
+                }
+            } else {
+                Self::parse_content_line(&line).transpose()
+            }
+        })
+    }
+
+    /// Creates a non-owning iterator over the series of live cell positions in the order written in the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Life106;
+    /// use life_backend::Position;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     #Life 1.06\n\
+    ///     0 -1\n\
+    ///     -1 0\n\
+    ///     0 0\n\
+    /// ";
+    /// let parser = Life106::new(pattern.as_bytes())?;
+    /// assert!(parser.live_cells().eq([Position(0, -1), Position(-1, 0), Position(0, 0)]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn live_cells(&self) -> impl Iterator<Item = Position<i64>> + '_ {
+        self.contents.iter().copied()
+    }
+
+    // The header line of Life 1.06 format
+    const HEADER: &'static str = "#Life 1.06";
+
+    // Converts the specified live cells into a string in Life 1.06 format with the specified description line, used by format::save_compact()
+    pub(super) fn to_string_with_description(cells: &[Position<usize>], description: &str) -> Result<String> {
+        let mut buf = format!("{}\n#D {description}\n", Self::HEADER);
+        for &pos in cells {
+            let Position(x, y) = Position::<i64>::try_from(pos)?;
+            buf += &format!("{x} {y}\n");
+        }
+        Ok(buf)
+    }
+
+    // Parses the line as a content line, returns None if the line is blank or a comment line
+    fn parse_content_line(line: &str) -> Result<Option<Position<i64>>> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        let fields: Vec<_> = line.split_whitespace().collect();
+        ensure!(fields.len() == 2, "The pattern is in wrong format");
+        let x = fields[0].parse().context("Invalid x-coordinate value")?;
+        let y = fields[1].parse().context("Invalid y-coordinate value")?;
+        Ok(Some(Position(x, y)))
+    }
+}
+
+// Trait implementations
+
+impl fmt::Display for Life106 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+        for Position(x, y) in self.live_cells() {
+            writeln!(f, "{x} {y}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Life106 {
+    type Err = anyhow::Error;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.as_bytes())
+    }
+}
+
+// Unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Board;
+    use std::fs::File;
+    #[test]
+    fn stream_file() -> Result<()> {
+        let file = File::open("patterns/glider.lif")?;
+        let result = Life106::stream(file).collect::<Result<Board<_>>>()?;
+        let expected: Board<_> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        assert_eq!(result, expected);
+        Ok(())
+    }
+    #[test]
+    fn stream_no_header() {
+        let pattern = "0 0\n";
+        let result = Life106::stream(pattern.as_bytes()).collect::<Result<Vec<_>>>();
+        assert!(result.is_err());
+    }
+    #[test]
+    fn stream_wrong_content() {
+        let pattern = "#Life 1.06\n0 0 0\n";
+        let result = Life106::stream(pattern.as_bytes()).collect::<Result<Vec<_>>>();
+        assert!(result.is_err());
+    }
+    #[test]
+    fn display() -> Result<()> {
+        let pattern = "#Life 1.06\n0 -1\n-1 0\n0 0\n";
+        let target: Life106 = pattern.parse()?;
+        assert_eq!(target.to_string(), pattern);
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use std::fmt;
 use std::iter::FromIterator;
 
 use super::{Plaintext, PlaintextLine};
-use crate::Position;
+use crate::{Position, Rule};
 
 /// A builder of [`Plaintext`].
 ///
@@ -58,13 +58,15 @@ use crate::Position;
 /// ```
 ///
 #[derive(Clone, Debug)]
-pub struct PlaintextBuilder<Name = PlaintextBuilderNoName, Comment = PlaintextBuilderNoComment>
+pub struct PlaintextBuilder<Name = PlaintextBuilderNoName, Comment = PlaintextBuilderNoComment, RuleSpec = PlaintextBuilderNoRule>
 where
     Name: PlaintextBuilderName,
     Comment: PlaintextBuilderComment,
+    RuleSpec: PlaintextBuilderRule,
 {
     name: Name,
     comment: Comment,
+    rule: RuleSpec,
     contents: HashSet<Position<usize>>,
 }
 
@@ -75,6 +77,9 @@ pub trait PlaintextBuilderName: Clone + fmt::Debug {
 pub trait PlaintextBuilderComment: Clone + fmt::Debug {
     fn drain(self) -> Option<String>;
 }
+pub trait PlaintextBuilderRule: Clone + fmt::Debug {
+    fn drain(self) -> Option<Rule>;
+}
 #[derive(Clone, Debug)]
 pub struct PlaintextBuilderNoName;
 impl PlaintextBuilderName for PlaintextBuilderNoName {
@@ -103,10 +108,24 @@ impl PlaintextBuilderComment for PlaintextBuilderWithComment {
         Some(self.0)
     }
 }
+#[derive(Clone, Debug)]
+pub struct PlaintextBuilderNoRule;
+impl PlaintextBuilderRule for PlaintextBuilderNoRule {
+    fn drain(self) -> Option<Rule> {
+        None
+    }
+}
+#[derive(Clone, Debug)]
+pub struct PlaintextBuilderWithRule(Rule);
+impl PlaintextBuilderRule for PlaintextBuilderWithRule {
+    fn drain(self) -> Option<Rule> {
+        Some(self.0)
+    }
+}
 
 // Inherent methods
 
-impl PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
+impl PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment, PlaintextBuilderNoRule> {
     /// Creates a builder that contains no live cells.
     ///
     /// # Examples
@@ -121,15 +140,17 @@ impl PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
         Self {
             name: PlaintextBuilderNoName,
             comment: PlaintextBuilderNoComment,
+            rule: PlaintextBuilderNoRule,
             contents: HashSet::new(),
         }
     }
 }
 
-impl<Name, Comment> PlaintextBuilder<Name, Comment>
+impl<Name, Comment, RuleSpec> PlaintextBuilder<Name, Comment, RuleSpec>
 where
     Name: PlaintextBuilderName,
     Comment: PlaintextBuilderComment,
+    RuleSpec: PlaintextBuilderRule,
 {
     /// Builds the [`Plaintext`] value.
     ///
@@ -163,6 +184,7 @@ where
             }
             None => Vec::new(),
         };
+        let rule = self.rule.drain();
         let contents_group_by_y = self.contents.into_iter().fold(HashMap::new(), |mut acc, Position(x, y)| {
             acc.entry(y).or_insert_with(Vec::new).push(x);
             acc
@@ -175,17 +197,27 @@ where
             }
             buf
         };
+        let width = contents_sorted
+            .iter()
+            .flat_map(|PlaintextLine(_, xs)| xs.iter())
+            .copied()
+            .max()
+            .map(|x| x.saturating_add(1))
+            .unwrap_or(0);
         Ok(Plaintext {
             name,
             comments,
+            rule,
+            width,
             contents: contents_sorted,
         })
     }
 }
 
-impl<Comment> PlaintextBuilder<PlaintextBuilderNoName, Comment>
+impl<Comment, RuleSpec> PlaintextBuilder<PlaintextBuilderNoName, Comment, RuleSpec>
 where
     Comment: PlaintextBuilderComment,
+    RuleSpec: PlaintextBuilderRule,
 {
     /// Set the name.
     ///
@@ -246,19 +278,21 @@ where
     /// # }
     /// ```
     ///
-    pub fn name(self, str: &str) -> PlaintextBuilder<PlaintextBuilderWithName, Comment> {
+    pub fn name(self, str: &str) -> PlaintextBuilder<PlaintextBuilderWithName, Comment, RuleSpec> {
         let name = PlaintextBuilderWithName(str.to_owned());
         PlaintextBuilder {
             name,
             comment: self.comment,
+            rule: self.rule,
             contents: self.contents,
         }
     }
 }
 
-impl<Name> PlaintextBuilder<Name, PlaintextBuilderNoComment>
+impl<Name, RuleSpec> PlaintextBuilder<Name, PlaintextBuilderNoComment, RuleSpec>
 where
     Name: PlaintextBuilderName,
+    RuleSpec: PlaintextBuilderRule,
 {
     /// Set the comment.
     /// If the argument includes newlines, the instance of [`Plaintext`] built by [`build()`] includes multiple comment lines.
@@ -305,11 +339,71 @@ where
     /// # }
     /// ```
     ///
-    pub fn comment(self, str: &str) -> PlaintextBuilder<Name, PlaintextBuilderWithComment> {
+    pub fn comment(self, str: &str) -> PlaintextBuilder<Name, PlaintextBuilderWithComment, RuleSpec> {
         let comment = PlaintextBuilderWithComment(str.to_owned());
         PlaintextBuilder {
             name: self.name,
             comment,
+            rule: self.rule,
+            contents: self.contents,
+        }
+    }
+}
+
+impl<Name, Comment> PlaintextBuilder<Name, Comment, PlaintextBuilderNoRule>
+where
+    Name: PlaintextBuilderName,
+    Comment: PlaintextBuilderComment,
+{
+    /// Set the rule.
+    /// Since Plaintext format has no field for the rule, the rule is written in a comment line such as `"!Rule: B36/S23"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::PlaintextBuilder;
+    /// use life_backend::{Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = [Position(1, 0), Position(0, 1)];
+    /// let rule = "B36/S23".parse::<Rule>()?;
+    /// let target = pattern
+    ///     .iter()
+    ///     .collect::<PlaintextBuilder>()
+    ///     .rule(rule.clone())
+    ///     .build()?;
+    /// assert_eq!(target.rule(), rule);
+    /// assert_eq!(format!("{target}"), "!Rule: B36/S23\n.O\nO.\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Code that calls [`rule()`] twice or more will fail at compile time.  For example:
+    ///
+    /// [`rule()`]: #method.rule
+    ///
+    /// ```compile_fail
+    /// use life_backend::format::PlaintextBuilder;
+    /// use life_backend::{Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = [Position(1, 0), Position(0, 1)];
+    /// let target = pattern
+    ///     .iter()
+    ///     .collect::<PlaintextBuilder>()
+    ///     .rule(Rule::conways_life())
+    ///     .rule(Rule::conways_life()) // Compile error
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn rule(self, rule: Rule) -> PlaintextBuilder<Name, Comment, PlaintextBuilderWithRule> {
+        let rule = PlaintextBuilderWithRule(rule);
+        PlaintextBuilder {
+            name: self.name,
+            comment: self.comment,
+            rule,
             contents: self.contents,
         }
     }
@@ -317,7 +411,7 @@ where
 
 // Trait implementations
 
-impl Default for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
+impl Default for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment, PlaintextBuilderNoRule> {
     /// Returns the default value of the type, same as the return value of [`new()`].
     ///
     /// [`new()`]: #method.new
@@ -328,10 +422,11 @@ impl Default for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComm
     }
 }
 
-impl<Name, Comment> PlaintextBuilder<Name, Comment>
+impl<Name, Comment, RuleSpec> PlaintextBuilder<Name, Comment, RuleSpec>
 where
     Name: PlaintextBuilderName,
     Comment: PlaintextBuilderComment,
+    RuleSpec: PlaintextBuilderRule,
 {
     // Implementation of public extend()
     #[inline]
@@ -343,7 +438,7 @@ where
     }
 }
 
-impl PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
+impl PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment, PlaintextBuilderNoRule> {
     // Implementation of public from_iter()
     fn from_iter<T>(iter: T) -> Self
     where
@@ -355,7 +450,7 @@ impl PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
     }
 }
 
-impl<'a> FromIterator<&'a Position<usize>> for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
+impl<'a> FromIterator<&'a Position<usize>> for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment, PlaintextBuilderNoRule> {
     /// Creates a value from a non-owning iterator over a series of [`&Position<usize>`].
     /// Each item in the series represents an immutable reference of a live cell position.
     ///
@@ -380,7 +475,7 @@ impl<'a> FromIterator<&'a Position<usize>> for PlaintextBuilder<PlaintextBuilder
     }
 }
 
-impl FromIterator<Position<usize>> for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment> {
+impl FromIterator<Position<usize>> for PlaintextBuilder<PlaintextBuilderNoName, PlaintextBuilderNoComment, PlaintextBuilderNoRule> {
     /// Creates a value from an owning iterator over a series of [`Position<usize>`].
     /// Each item in the series represents a moved live cell position.
     ///
@@ -405,10 +500,11 @@ impl FromIterator<Position<usize>> for PlaintextBuilder<PlaintextBuilderNoName,
     }
 }
 
-impl<'a, Name, Comment> Extend<&'a Position<usize>> for PlaintextBuilder<Name, Comment>
+impl<'a, Name, Comment, RuleSpec> Extend<&'a Position<usize>> for PlaintextBuilder<Name, Comment, RuleSpec>
 where
     Name: PlaintextBuilderName,
     Comment: PlaintextBuilderComment,
+    RuleSpec: PlaintextBuilderRule,
 {
     /// Extends the builder with the contents of the specified non-owning iterator over the series of [`&Position<usize>`].
     /// Each item in the series represents an immutable reference of a live cell position.
@@ -435,10 +531,11 @@ where
     }
 }
 
-impl<Name, Comment> Extend<Position<usize>> for PlaintextBuilder<Name, Comment>
+impl<Name, Comment, RuleSpec> Extend<Position<usize>> for PlaintextBuilder<Name, Comment, RuleSpec>
 where
     Name: PlaintextBuilderName,
     Comment: PlaintextBuilderComment,
+    RuleSpec: PlaintextBuilderRule,
 {
     /// Extends the builder with the contents of the specified owning iterator over the series of [`Position<usize>`].
     /// Each item in the series represents a moved live cell position.
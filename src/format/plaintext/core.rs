@@ -1,9 +1,9 @@
-use anyhow::Result;
 use std::fmt;
 use std::io::Read;
 use std::str::FromStr;
 
 use super::{PlaintextLine, PlaintextParser};
+use crate::format::{FormatError, PatternSummary};
 use crate::{Format, Position, Rule};
 
 /// A representation for Plaintext file format.
@@ -50,6 +50,8 @@ use crate::{Format, Position, Rule};
 pub struct Plaintext {
     pub(super) name: Option<String>,
     pub(super) comments: Vec<String>,
+    pub(super) rule: Option<Rule>,
+    pub(super) width: usize,
     pub(super) contents: Vec<PlaintextLine>,
 }
 
@@ -77,7 +79,7 @@ impl Plaintext {
     /// ```
     ///
     #[inline]
-    pub fn new<R>(read: R) -> Result<Self>
+    pub fn new<R>(read: R) -> Result<Self, FormatError>
     where
         R: Read,
     {
@@ -133,6 +135,65 @@ impl Plaintext {
         &self.comments
     }
 
+    /// Returns the rule of the pattern.
+    ///
+    /// Plaintext format has no field for the rule, so the rule is written in a comment line such as `"!Rule: B36/S23"`.
+    /// If the pattern has no such line, the rule is [Conway's Game of Life](Rule::conways_life).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Plaintext;
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     !Name: Replicator\n\
+    ///     !Rule: B36/S23\n\
+    ///     ..OOO\n\
+    ///     .O..O\n\
+    ///     O...O\n\
+    ///     O..O.\n\
+    ///     OOO..\n\
+    /// ";
+    /// let parser = Plaintext::new(pattern.as_bytes())?;
+    /// assert_eq!(parser.rule(), "B36/S23".parse::<Rule>()?);
+    /// assert!(parser.comments().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn rule(&self) -> Rule {
+        self.rule.clone().unwrap_or_else(Rule::conways_life)
+    }
+
+    /// Returns the width of the pattern.
+    ///
+    /// For a parsed pattern, this is the maximum length of the content lines, including trailing dead cells.
+    /// For a pattern built by [`PlaintextBuilder`], this is the maximum x-coordinate value of live cells plus one.
+    ///
+    /// [`PlaintextBuilder`]: crate::format::PlaintextBuilder
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     !Name: T-tetromino\n\
+    ///     OOO.\n\
+    ///     .O..\n\
+    /// ";
+    /// let parser = Plaintext::new(pattern.as_bytes())?;
+    /// assert_eq!(parser.width(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
     /// Creates an owning iterator over the series of live cell positions in ascending order.
     ///
     /// # Examples
@@ -155,17 +216,49 @@ impl Plaintext {
     pub fn live_cells(&self) -> impl Iterator<Item = Position<usize>> + '_ {
         self.contents.iter().flat_map(|PlaintextLine(y, xs)| xs.iter().map(move |x| Position(*x, *y)))
     }
+
+    /// Creates a non-owning iterator over the series of rows including live cells, in ascending order of the y-coordinate value.
+    ///
+    /// Each item is `(y, xs)`, where `y` is the y-coordinate value of the row and `xs` is the x-coordinate values of live cells in the row in ascending order.
+    /// Rows without live cells are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     !Name: T-tetromino\n\
+    ///     OOO\n\
+    ///     .O.\n\
+    /// ";
+    /// let parser = Plaintext::new(pattern.as_bytes())?;
+    /// let mut rows = parser.rows();
+    /// assert_eq!(rows.next(), Some((0, &[0, 1, 2][..])));
+    /// assert_eq!(rows.next(), Some((1, &[1][..])));
+    /// assert_eq!(rows.next(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn rows(&self) -> impl Iterator<Item = (usize, &[usize])> + '_ {
+        self.contents.iter().map(|PlaintextLine(y, xs)| (*y, xs.as_slice()))
+    }
 }
 
 // Trait implementations
 
 impl Format for Plaintext {
+    #[inline]
     fn rule(&self) -> Rule {
-        Rule::conways_life()
+        self.rule()
     }
     fn live_cells(&self) -> Box<dyn Iterator<Item = Position<usize>> + '_> {
         Box::new(self.live_cells())
     }
+    fn summary(&self) -> PatternSummary {
+        PatternSummary::from_format(self).with_name_and_comments(self.name(), self.comments().clone())
+    }
 }
 
 impl fmt::Display for Plaintext {
@@ -176,8 +269,12 @@ impl fmt::Display for Plaintext {
         for line in self.comments() {
             writeln!(f, "!{line}")?;
         }
+        if let Some(rule) = &self.rule {
+            writeln!(f, "!Rule: {rule}")?;
+        }
         if !self.contents.is_empty() {
             let max_x = self.contents.iter().flat_map(|PlaintextLine(_, xs)| xs.iter()).copied().max().unwrap(); // this unwrap() never panic because flat_map() always returns at least one value under !self.contents.is_empty()
+            let max_x = max_x.max(self.width.saturating_sub(1)); // pad trailing dead cells up to the recorded width
             let dead_cell_chars = ".".repeat(max_x) + "."; // this code avoids `".".repeat(max_x + 1)` because `max_x + 1` overflows if max_x == usize::MAX
             let mut prev_y = 0;
             for PlaintextLine(curr_y, xs) in &self.contents {
@@ -205,7 +302,7 @@ impl fmt::Display for Plaintext {
 }
 
 impl FromStr for Plaintext {
-    type Err = anyhow::Error;
+    type Err = FormatError;
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Self::new(s.as_bytes())
@@ -1,13 +1,18 @@
-use anyhow::{anyhow, Result};
 use std::io::{BufRead as _, BufReader, Read};
 
 use super::{Plaintext, PlaintextLine};
+use crate::format::{FormatError, FormatErrorKind};
+use crate::Rule;
+
+type Result<T> = std::result::Result<T, FormatErrorKind>;
 
 // The parser of Plaintext format, used during constructing of Plaintext
 pub(super) struct PlaintextParser {
     name: Option<String>,
     comments: Vec<String>,
+    rule: Option<Rule>,
     lines: usize,
+    width: usize,
     contents: Vec<PlaintextLine>,
 }
 
@@ -15,18 +20,21 @@ pub(super) struct PlaintextParser {
 
 impl PlaintextParser {
     // Parses the specified implementor of Read (e.g., `File`, `&[u8]`) into Plaintext
-    pub(super) fn parse<R>(read: R) -> Result<Plaintext>
+    pub(super) fn parse<R>(read: R) -> std::result::Result<Plaintext, FormatError>
     where
         R: Read,
     {
-        let parser = BufReader::new(read).lines().try_fold(Self::new(), |mut buf, line| {
+        let parser = BufReader::new(read).lines().enumerate().try_fold(Self::new(), |mut buf, (i, line)| {
             let line = line?;
-            buf.push(&line)?;
-            Ok::<_, anyhow::Error>(buf)
+            let line = if i == 0 { line.strip_prefix('\u{FEFF}').unwrap_or(&line) } else { &line }; // a leading BOM is ignored
+            buf.push(line).map_err(|e| FormatError::at_line(e, i + 1))?;
+            Ok::<_, FormatError>(buf)
         })?;
         Ok(Plaintext {
             name: parser.name,
             comments: parser.comments,
+            rule: parser.rule,
+            width: parser.width,
             contents: parser.contents,
         })
     }
@@ -36,7 +44,9 @@ impl PlaintextParser {
         Self {
             name: None,
             comments: Vec::new(),
+            rule: None,
             lines: 0,
+            width: 0,
             contents: Vec::new(),
         }
     }
@@ -49,6 +59,12 @@ impl PlaintextParser {
                 return Ok(());
             }
         }
+        if self.rule.is_none() && self.lines == 0 {
+            if let Some(rule) = Self::parse_rule_line(line) {
+                self.rule = Some(rule);
+                return Ok(());
+            }
+        }
         if self.lines == 0 {
             if let Some(comment) = Self::parse_comment_line(line) {
                 self.comments.push(comment.to_owned());
@@ -56,6 +72,7 @@ impl PlaintextParser {
             }
         }
         let content = Self::parse_content_line(line)?;
+        self.width = self.width.max(line.chars().count());
         if !content.is_empty() {
             self.contents.push(PlaintextLine(self.lines, content));
         }
@@ -77,10 +94,16 @@ impl PlaintextParser {
         }
     }
 
-    // Parses the line as a name line
+    // Parses the line as a name line, tolerating the spaces around the name (e.g., "!Name:name", "!Name: ")
     #[inline]
     fn parse_name_line(line: &str) -> Option<&str> {
-        Self::parse_prefixed_line("!Name: ", line)
+        Self::parse_prefixed_line("!Name:", line).map(str::trim)
+    }
+
+    // Parses the line as a rule line (e.g., "!Rule: B36/S23", "!Rule: HighLife"), where the line with an invalid rule is not a rule line
+    fn parse_rule_line(line: &str) -> Option<Rule> {
+        let rule_str = Self::parse_prefixed_line("!Rule:", line)?.trim();
+        rule_str.parse().ok().or_else(|| Rule::from_name(rule_str))
     }
 
     // Parses the line as a comment line
@@ -96,7 +119,7 @@ impl PlaintextParser {
             .filter_map(|(i, c)| match c {
                 '.' => None,
                 'O' => Some(Ok(i)),
-                _ => Some(Err(anyhow!("Invalid character found in the pattern"))),
+                _ => Some(Err(FormatErrorKind::InvalidCharacter)),
             })
             .collect()
     }
@@ -1,7 +1,8 @@
 use anyhow::Result;
 
 use super::{Plaintext, PlaintextBuilder, PlaintextLine};
-use crate::Position;
+use crate::format::FormatErrorKind;
+use crate::{Format, Position, Rule};
 
 fn do_check(target: &Plaintext, expected_name: &Option<&str>, expected_comments: &[&str], expected_contents: &[PlaintextLine]) {
     let expected_name = expected_name.map(String::from);
@@ -23,9 +24,12 @@ fn do_new_test_to_be_passed(pattern: &str, expected_name: &Option<&str>, expecte
     Ok(())
 }
 
-fn do_new_test_to_be_failed(pattern: &str) {
+fn do_new_test_to_be_failed<F>(pattern: &str, pred: F)
+where
+    F: Fn(&FormatErrorKind) -> bool,
+{
     let target = Plaintext::new(pattern.as_bytes());
-    assert!(target.is_err());
+    assert!(matches!(&target, Err(e) if pred(e.kind())));
 }
 
 fn do_from_str_test_to_be_passed(pattern: &str, expected_name: &Option<&str>, expected_comments: &[&str], expected_contents: &[PlaintextLine]) -> Result<()> {
@@ -47,6 +51,22 @@ fn new_header() -> Result<()> {
     do_new_test_to_be_passed(pattern, &Some("test"), &Vec::new(), &Vec::new())
 }
 
+#[test]
+fn new_header_without_space() -> Result<()> {
+    let pattern = "!Name:test\n";
+    let target = Plaintext::new(pattern.as_bytes())?;
+    do_check(&target, &Some("test"), &Vec::new(), &Vec::new());
+    Ok(())
+}
+
+#[test]
+fn new_header_without_name() -> Result<()> {
+    let pattern = "!Name:\n";
+    let target = Plaintext::new(pattern.as_bytes())?;
+    do_check(&target, &Some(""), &Vec::new(), &Vec::new());
+    Ok(())
+}
+
 #[test]
 fn new_no_header_but_comment() -> Result<()> {
     let pattern = "!comment\n";
@@ -88,10 +108,19 @@ fn new_header_comments_contents() -> Result<()> {
     )
 }
 
+#[test]
+fn new_header_contents_with_trailing_dead_cells() -> Result<()> {
+    let pattern = concat!("!Name: test\n", ".O.\n", "O..\n");
+    do_new_test_to_be_passed(pattern, &Some("test"), &Vec::new(), &[PlaintextLine(0, vec![1]), PlaintextLine(1, vec![0])])?;
+    let target = Plaintext::new(pattern.as_bytes())?;
+    assert_eq!(target.width(), 3);
+    Ok(())
+}
+
 #[test]
 fn new_wrong_header() {
     let pattern = "_\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidCharacter))
 }
 
 #[test]
@@ -103,13 +132,13 @@ fn new_duplicate_header() -> Result<()> {
 #[test]
 fn new_wrong_content_without_comment() {
     let pattern = concat!("!Name: test\n", "_\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidCharacter))
 }
 
 #[test]
 fn new_wrong_content_with_comment() {
     let pattern = concat!("!Name: test\n", "!\n", "_\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidCharacter))
 }
 
 #[test]
@@ -117,6 +146,7 @@ fn build() -> Result<()> {
     let pattern = [Position(1, 0), Position(0, 1)];
     let target = pattern.iter().collect::<PlaintextBuilder>().build()?;
     do_check(&target, &None, &Vec::new(), &[PlaintextLine(0, vec![1]), PlaintextLine(1, vec![0])]);
+    assert_eq!(target.width(), 2);
     Ok(())
 }
 
@@ -190,3 +220,79 @@ fn from_str() -> Result<()> {
         &[PlaintextLine(0, vec![1]), PlaintextLine(1, vec![0])],
     )
 }
+
+#[test]
+fn summary() -> Result<()> {
+    let pattern = concat!("!Name: test\n", "!comment\n", ".O\n", "O.\n");
+    let target = Plaintext::new(pattern.as_bytes())?;
+    let summary = Format::summary(&target);
+    assert_eq!(summary.population(), 2);
+    assert_eq!(summary.bounding_box(), &[Position(0, 0), Position(1, 1)].iter().collect());
+    assert_eq!(summary.rule(), &Rule::conways_life());
+    assert_eq!(summary.name(), Some("test"));
+    assert_eq!(summary.comments(), &["comment"]);
+    Ok(())
+}
+
+#[test]
+fn new_with_bom() -> Result<()> {
+    let pattern = concat!("!Name: test\n", "!comment\n", ".O\n", "O.\n");
+    let expected = Plaintext::new(pattern.as_bytes())?;
+    let target = Plaintext::new(format!("\u{FEFF}{pattern}").as_bytes())?;
+    assert_eq!(target.name(), expected.name());
+    assert_eq!(target.comments(), expected.comments());
+    assert!(target.live_cells().eq(expected.live_cells()));
+    assert_eq!(target.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn new_invalid_character_line_number() {
+    let pattern = concat!("!Name: test\n", ".O\n", "OX\n");
+    let target = Plaintext::new(pattern.as_bytes());
+    assert!(matches!(&target, Err(e) if e.line() == Some(3)));
+    assert!(matches!(&target, Err(e) if e.to_string() == "line 3: Invalid character found in the pattern"));
+}
+
+#[test]
+fn build_rule_round_trip() -> Result<()> {
+    let rule = Rule::from_name("HighLife").unwrap();
+    let pattern = [Position(2, 0), Position(3, 0), Position(4, 0), Position(1, 1), Position(0, 2)];
+    let target = pattern.iter().collect::<PlaintextBuilder>().name("test").rule(rule.clone()).build()?;
+    let result: Plaintext = target.to_string().parse()?;
+    assert_eq!(result.rule(), rule);
+    assert_eq!(Format::rule(&result), rule);
+    assert!(result.comments().is_empty());
+    assert!(result.live_cells().eq(pattern.iter().copied()));
+    assert_eq!(result.to_string(), target.to_string());
+    Ok(())
+}
+
+#[test]
+fn new_rule_line() -> Result<()> {
+    let pattern = concat!("!Name: test\n", "!comment\n", "!Rule: B36/S23\n", ".O\n", "O.\n");
+    let target = Plaintext::new(pattern.as_bytes())?;
+    assert_eq!(target.rule(), Rule::from_name("HighLife").unwrap());
+    do_check(&target, &Some("test"), &["comment"], &[PlaintextLine(0, vec![1]), PlaintextLine(1, vec![0])]);
+    assert_eq!(target.to_string(), pattern);
+    Ok(())
+}
+
+#[test]
+fn new_invalid_rule_line_as_comment() -> Result<()> {
+    let pattern = concat!("!Rule: unknown\n", ".O\n");
+    let target = Plaintext::new(pattern.as_bytes())?;
+    assert_eq!(target.rule(), Rule::conways_life());
+    do_check(&target, &None, &["Rule: unknown"], &[PlaintextLine(0, vec![1])]);
+    assert_eq!(target.to_string(), pattern);
+    Ok(())
+}
+
+#[test]
+fn rows_with_blank_lines() -> Result<()> {
+    let pattern = concat!("O..\n", "...\n", ".OO\n");
+    let target = Plaintext::new(pattern.as_bytes())?;
+    let result: Vec<_> = target.rows().map(|(y, xs)| (y, xs.to_vec())).collect();
+    assert_eq!(result, vec![(0, vec![0]), (2, vec![1, 2])]);
+    Ok(())
+}
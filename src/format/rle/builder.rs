@@ -1,10 +1,13 @@
-use anyhow::{ensure, Result};
+use anyhow::{ensure, Context as _, Result};
+use num_traits::{One, ToPrimitive, Zero};
 use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
 use std::fmt;
+use std::hash::Hash;
 use std::iter::FromIterator;
 
 use super::{Rle, RleHeader, RleRunsTriple};
-use crate::{Position, Rule};
+use crate::{Board, Position, Rule};
 
 /// A builder of [`Rle`].
 ///
@@ -162,6 +165,57 @@ impl RleBuilder<RleBuilderNoName, RleBuilderNoCreated, RleBuilderNoComment, RleB
             contents: HashSet::new(),
         }
     }
+
+    /// Creates a builder from the live cells of the specified board, translated so that the minimum corner of the bounding box lands on the origin.
+    ///
+    /// Returns the builder and the offset, i.e., the position on the board corresponding to the origin of the builder.
+    /// The original position of each cell is the position in the builder plus the offset, so the offset can be recorded, e.g., as `#CXRLE Pos=x,y`.
+    /// If the board is empty, the offset is `Position(0, 0)`.
+    ///
+    /// Returns an error if the width or the height of the board cannot be represented as `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::RleBuilder;
+    /// use life_backend::{Board, Position};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let board: Board<i16> = [Position(-3, -5), Position(-2, -4)].iter().collect();
+    /// let (builder, offset) = RleBuilder::from_board_normalized(&board)?;
+    /// assert_eq!(offset, Position(-3, -5));
+    /// let target = builder.build()?;
+    /// assert_eq!(target.to_string(), "x = 2, y = 2, rule = B3/S23\no$bo!\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_board_normalized<T>(board: &Board<T>) -> Result<(Self, Position<T>)>
+    where
+        T: Eq + Hash + Copy + PartialOrd + Zero + One + ToPrimitive,
+    {
+        // Returns the distance from `min` to `v` as usize, without overflowing T
+        fn distance<T>(v: T, min: T) -> Option<usize>
+        where
+            T: ToPrimitive,
+        {
+            usize::try_from(v.to_i128()? - min.to_i128()?).ok()
+        }
+        let bbox = board.bounding_box();
+        if bbox.is_empty() {
+            return Ok((Self::new(), Position(T::zero(), T::zero())));
+        }
+        let (min_x, min_y) = (*bbox.x().start(), *bbox.y().start());
+        let contents = board
+            .iter()
+            .map(|&Position(x, y)| {
+                let x = distance(x, min_x).context("The x-coordinate value cannot be converted into usize")?;
+                let y = distance(y, min_y).context("The y-coordinate value cannot be converted into usize")?;
+                Ok(Position(x, y))
+            })
+            .collect::<Result<HashSet<_>>>()?;
+        let builder = Self { contents, ..Self::new() };
+        Ok((builder, Position(min_x, min_y)))
+    }
 }
 
 impl<Name, Created, Comment, RuleSpec> RleBuilder<Name, Created, Comment, RuleSpec>
@@ -173,6 +227,12 @@ where
 {
     /// Builds the [`Rle`] value.
     ///
+    /// The content of the built value is always encoded in the shortest form when it is written via [`Display`]:
+    /// dead cells at the end of a line are never written before `$` or `!`, consecutive line ends are collapsed into a single run (e.g., `3$`),
+    /// empty lines at the end of the pattern are never written, and a run count of 1 is omitted.
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
     /// # Examples
     ///
     /// ```
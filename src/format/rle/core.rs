@@ -1,9 +1,9 @@
-use anyhow::Result;
 use std::fmt;
 use std::io::Read;
 use std::str::FromStr;
 
 use super::{RleHeader, RleParser, RleRunsTriple};
+use crate::format::{FormatError, PatternSummary};
 use crate::{Format, Position, Rule};
 
 /// A representation for RLE file format.
@@ -77,13 +77,48 @@ impl Rle {
     /// ```
     ///
     #[inline]
-    pub fn new<R>(read: R) -> Result<Self>
+    pub fn new<R>(read: R) -> Result<Self, FormatError>
     where
         R: Read,
     {
         RleParser::parse(read)
     }
 
+    /// Creates from the specified implementor of [`Read`], such as [`File`] or `&[u8]`, tolerating the content that exceeds the size written in the header.
+    ///
+    /// Unlike [`new()`], this method does not return an error if the content exceeds the width or the height written in the header.
+    /// Instead, the width and the height are widened to encompass all live cells.
+    ///
+    /// [`Read`]: std::io::Read
+    /// [`File`]: std::fs::File
+    /// [`new()`]: #method.new
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Rle;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     #N T-tetromino\n\
+    ///     x = 2, y = 1\n\
+    ///     3o$bo!\n\
+    /// ";
+    /// assert!(Rle::new(pattern.as_bytes()).is_err());
+    /// let parser = Rle::new_lenient(pattern.as_bytes())?;
+    /// assert_eq!(parser.width(), 3);
+    /// assert_eq!(parser.height(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn new_lenient<R>(read: R) -> Result<Self, FormatError>
+    where
+        R: Read,
+    {
+        RleParser::parse_lenient(read)
+    }
+
     /// Returns the width written in the pattern.
     ///
     /// # Examples
@@ -156,6 +191,11 @@ impl Rle {
 
     /// Returns comments of the pattern.
     ///
+    /// The comment lines before the header line are held verbatim in the order written in the pattern, without any normalization,
+    /// and [`Display`] writes them back unchanged, so the comment section of the pattern is reproduced exactly.
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
     /// # Examples
     ///
     /// ```
@@ -214,6 +254,68 @@ impl Rle {
             })
             .flat_map(|(y, x, num)| (x..(x + num)).map(move |x| Position(x, y)))
     }
+
+    /// Returns the string in RLE format, writing each row of the pattern in its own line.
+    ///
+    /// Unlike [`Display`], which wraps the content lines at 70 columns, each content line of the returned string ends at `'$'` or `'!'`,
+    /// i.e., the content is never wrapped in the middle of a row however long the row is.
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::format::Rle;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "\
+    ///     #N Glider\n\
+    ///     x = 3, y = 3, rule = B3/S23\n\
+    ///     bo$2bo$3o!\n\
+    /// ";
+    /// let parser = Rle::new(pattern.as_bytes())?;
+    /// assert_eq!(parser.to_string_rows(), "#N Glider\nx = 3, y = 3, rule = B3/S23\nbo$\n2bo$\n3o!\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn to_string_rows(&self) -> String {
+        let mut buf = String::new();
+        for line in self.comments() {
+            buf += line;
+            buf.push('\n');
+        }
+        buf += &self.header_line();
+        buf.push('\n');
+        for x in &self.contents {
+            if x.pad_lines > 0 {
+                buf += &convert_run_to_string(x.pad_lines, '$');
+                buf.push('\n');
+            }
+            for (run_count, tag_char) in [(x.pad_dead_cells, 'b'), (x.live_cells, 'o')] {
+                if run_count > 0 {
+                    buf += &convert_run_to_string(run_count, tag_char);
+                }
+            }
+        }
+        buf += "!\n";
+        buf
+    }
+
+    // Returns the header line, without the trailing newline
+    fn header_line(&self) -> String {
+        format!("x = {}, y = {}, rule = {}", self.width(), self.height(), self.rule().as_str())
+    }
+}
+
+// Converts the run into the string, e.g., "3o", omitting the run count if it is 1
+fn convert_run_to_string(run_count: usize, tag_char: char) -> String {
+    if run_count > 1 {
+        let mut buf = run_count.to_string();
+        buf.push(tag_char);
+        buf
+    } else {
+        tag_char.to_string()
+    }
 }
 
 // Trait implementations
@@ -225,20 +327,20 @@ impl Format for Rle {
     fn live_cells(&self) -> Box<dyn Iterator<Item = Position<usize>> + '_> {
         Box::new(self.live_cells())
     }
+    fn summary(&self) -> PatternSummary {
+        // The name is taken from the first "#N" line, and the comments are the comment lines as they are
+        let name = self
+            .comments()
+            .iter()
+            .find_map(|line| line.strip_prefix("#N"))
+            .map(|name| name.trim().to_string());
+        PatternSummary::from_format(self).with_name_and_comments(name, self.comments().clone())
+    }
 }
 
 impl fmt::Display for Rle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         const MAX_LINE_WIDTH: usize = 70;
-        fn convert_run_to_string(run_count: usize, tag_char: char) -> String {
-            if run_count > 1 {
-                let mut buf = run_count.to_string();
-                buf.push(tag_char);
-                buf
-            } else {
-                tag_char.to_string()
-            }
-        }
         fn flush_buf(f: &mut fmt::Formatter, buf: &mut String) -> Result<(), fmt::Error> {
             writeln!(f, "{buf}")?;
             Ok(())
@@ -254,7 +356,7 @@ impl fmt::Display for Rle {
         for line in self.comments() {
             writeln!(f, "{line}")?;
         }
-        writeln!(f, "x = {}, y = {}, rule = {}", self.width(), self.height(), self.rule())?;
+        writeln!(f, "{}", self.header_line())?;
         let mut buf = String::new();
         for x in &self.contents {
             for (run_count, tag_char) in [(x.pad_lines, '$'), (x.pad_dead_cells, 'b'), (x.live_cells, 'o')] {
@@ -271,7 +373,7 @@ impl fmt::Display for Rle {
 }
 
 impl FromStr for Rle {
-    type Err = anyhow::Error;
+    type Err = FormatError;
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Self::new(s.as_bytes())
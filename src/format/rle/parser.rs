@@ -1,11 +1,14 @@
-use anyhow::{ensure, Context as _, Result};
 use std::io::{BufRead as _, BufReader, Read};
 
 use super::{Rle, RleHeader, RleRunsTriple};
-use crate::Rule;
+use crate::format::{FormatError, FormatErrorKind};
+use crate::{Position, Rule};
+
+type Result<T> = std::result::Result<T, FormatErrorKind>;
 
 // The parser of RLE format, used during constructing of Rle
 pub(super) struct RleParser {
+    lenient: bool,
     comments: Vec<String>,
     header: Option<RleHeader>,
     contents: Vec<RleRun>,
@@ -25,25 +28,55 @@ struct RleRun(usize, RleTag);
 
 impl RleParser {
     // Parses the specified implementor of Read (e.g., `File`, `&[u8]`) into Rle
-    pub(super) fn parse<R>(read: R) -> Result<Rle>
+    #[inline]
+    pub(super) fn parse<R>(read: R) -> std::result::Result<Rle, FormatError>
+    where
+        R: Read,
+    {
+        Self::parse_with(read, false)
+    }
+
+    // Parses the specified implementor of Read into Rle, widening the width and the height in the header to encompass the content
+    #[inline]
+    pub(super) fn parse_lenient<R>(read: R) -> std::result::Result<Rle, FormatError>
+    where
+        R: Read,
+    {
+        Self::parse_with(read, true)
+    }
+
+    // Implementation of parse() and parse_lenient()
+    fn parse_with<R>(read: R, lenient: bool) -> std::result::Result<Rle, FormatError>
     where
         R: Read,
     {
-        let parser = BufReader::new(read).lines().try_fold(Self::new(), |mut buf, line| {
+        let parser = BufReader::new(read).lines().enumerate().try_fold(Self::new(lenient), |mut buf, (i, line)| {
             let line = line?;
-            buf.push(&line)?;
-            Ok::<_, anyhow::Error>(buf)
+            let line = if i == 0 { line.strip_prefix('\u{FEFF}').unwrap_or(&line) } else { &line }; // a leading BOM is ignored
+            buf.push(line).map_err(|e| FormatError::at_line(e, i + 1))?;
+            Ok::<_, FormatError>(buf)
         })?;
-        ensure!(parser.finished, "The terminal symbol not found");
-        let header = parser.header.context("Header line not found in the pattern")?;
+        let header = parser.header.ok_or(FormatErrorKind::MissingHeader)?;
+        if !parser.finished {
+            return Err(FormatErrorKind::UnterminatedPattern.into());
+        }
         let comments = parser.comments;
         let contents = Self::convert_runs_to_triples(&parser.contents);
-        Ok(Rle { header, comments, contents })
+        let mut rle = Rle { header, comments, contents };
+        if lenient {
+            let (width, height) = rle
+                .live_cells()
+                .fold((0, 0), |(width, height), Position(x, y)| (width.max(x + 1), height.max(y + 1)));
+            rle.header.width = rle.header.width.max(width);
+            rle.header.height = rle.header.height.max(height);
+        }
+        Ok(rle)
     }
 
     // Creates an empty parser
-    fn new() -> Self {
+    fn new(lenient: bool) -> Self {
         Self {
+            lenient,
             comments: Vec::new(),
             header: None,
             contents: Vec::new(),
@@ -57,8 +90,12 @@ impl RleParser {
         if let Some(header) = &self.header {
             if !self.finished {
                 let (contents, terminated) = Self::parse_content_line(line)?;
-                let advanced_position = Self::advanced_position(header, self.position, &contents)?;
-                self.contents.extend(contents.into_iter());
+                let advanced_position = if self.lenient {
+                    Self::advanced_position_unchecked(self.position, &contents)?
+                } else {
+                    Self::advanced_position(header, self.position, &contents)?
+                };
+                self.contents.extend(contents);
                 self.position = advanced_position;
                 self.finished = terminated;
             }
@@ -79,26 +116,37 @@ impl RleParser {
     // Parses the line as a header line
     fn parse_header_line(line: &str) -> Result<RleHeader> {
         fn check_variable_name(expected_name: &str, label: &str, name: &str) -> Result<()> {
-            ensure!(name == expected_name, format!("{label} variable in the header line is not \"{expected_name}\""));
+            if name != expected_name {
+                return Err(FormatErrorKind::InvalidHeader(format!(
+                    "{label} variable in the header line is not \"{expected_name}\""
+                )));
+            }
             Ok(())
         }
         fn parse_as_number((name, val_str): (&str, &str)) -> Result<usize> {
-            val_str.parse().with_context(|| format!("Invalid {name} value"))
+            val_str.parse().map_err(|_| FormatErrorKind::InvalidHeader(format!("Invalid {name} value")))
+        }
+        fn parse_as_rule((_, val_str): (&str, &str)) -> Result<Rule> {
+            val_str.parse().ok().or_else(|| Rule::from_name(val_str)).ok_or(FormatErrorKind::InvalidRule)
         }
-        fn parse_as_rule((name, val_str): (&str, &str)) -> Result<Rule> {
-            val_str.parse().with_context(|| format!("Invalid {name} value"))
+        fn invalid_header(detail: &str) -> FormatErrorKind {
+            FormatErrorKind::InvalidHeader(detail.to_owned())
         }
         let fields = line
             .split(',')
             .enumerate()
             .map(|(index, str)| {
-                ensure!(index <= 2, "Too many fields in the header line");
+                if index > 2 {
+                    return Err(invalid_header("Too many fields in the header line"));
+                }
                 str.find('=')
                     .map(|pos| (str[..pos].trim(), str[(pos + 1)..].trim()))
-                    .context("Parse error in the header line")
+                    .ok_or_else(|| invalid_header("Parse error in the header line"))
             })
             .collect::<Result<Vec<_>>>()?;
-        ensure!(fields.len() >= 2, "Too few fields in the header line");
+        if fields.len() < 2 {
+            return Err(invalid_header("Too few fields in the header line"));
+        }
         check_variable_name("x", "1st", fields[0].0)?;
         let width = parse_as_number(fields[0])?;
         check_variable_name("y", "2nd", fields[1].0)?;
@@ -120,26 +168,33 @@ impl RleParser {
                 let line_remain = line.trim_start();
                 let (run_count_str, line_remain) = line_remain.split_at(line_remain.find(|c: char| !c.is_ascii_digit()).unwrap_or(line_remain.len()));
                 let Some(tag_char) = line_remain.chars().next() else {
-                    ensure!(run_count_str.is_empty(), "The pattern is in wrong format");
+                    if !run_count_str.is_empty() {
+                        return Err(FormatErrorKind::InvalidContent);
+                    }
                     break false;
                 };
                 (run_count_str, tag_char, &line_remain[1..])
             };
             let run_count = if !run_count_str.is_empty() {
-                Some(run_count_str.parse().unwrap()) // this unwrap never panic because num_str only includes ascii digits
+                // run_count_str only includes ascii digits, so this fails only if the run count exceeds usize
+                Some(run_count_str.parse().map_err(|_| FormatErrorKind::InvalidContent)?)
             } else {
                 None
             };
             let tag = match tag_char {
                 '!' => {
-                    ensure!(run_count.is_none(), "The pattern is in wrong format");
+                    if run_count.is_some() {
+                        return Err(FormatErrorKind::InvalidContent);
+                    }
                     break true;
                 }
                 'o' => RleTag::AliveCell,
                 'b' => RleTag::DeadCell,
                 '$' => RleTag::EndOfLine,
                 c => {
-                    ensure!(!c.is_whitespace(), "The pattern is in wrong format");
+                    if c.is_whitespace() {
+                        return Err(FormatErrorKind::InvalidContent);
+                    }
                     RleTag::AliveCell
                 }
             };
@@ -151,20 +206,35 @@ impl RleParser {
 
     // Calculates the advanced position
     fn advanced_position(header: &RleHeader, current_position: (usize, usize), contents_to_be_append: &[RleRun]) -> Result<(usize, usize)> {
-        ensure!(contents_to_be_append.is_empty() || header.height > 0, "The pattern exceeds specified height"); // this check is required for the header with "y = 0"
+        // this check is required for the header with "y = 0"
+        if !contents_to_be_append.is_empty() && header.height == 0 {
+            return Err(FormatErrorKind::ExceedsHeight);
+        }
         contents_to_be_append
             .iter()
             .try_fold(current_position, |(curr_x, curr_y), RleRun(count, tag)| match tag {
-                RleTag::EndOfLine => {
-                    let next_y = curr_y + count;
-                    ensure!(next_y < header.height, "The pattern exceeds specified height");
-                    Ok((0, next_y))
-                }
-                _ => {
-                    let next_x = curr_x + count;
-                    ensure!(next_x <= header.width, "The pattern exceeds specified width");
-                    Ok((next_x, curr_y))
-                }
+                RleTag::EndOfLine => match curr_y.checked_add(*count) {
+                    Some(next_y) if next_y < header.height => Ok((0, next_y)),
+                    _ => Err(FormatErrorKind::ExceedsHeight),
+                },
+                _ => match curr_x.checked_add(*count) {
+                    Some(next_x) if next_x <= header.width => Ok((next_x, curr_y)),
+                    _ => Err(FormatErrorKind::ExceedsWidth),
+                },
+            })
+    }
+
+    // Calculates the advanced position without checking the width and the height in the header,
+    // but the width and the height including the position must still be representable in usize
+    fn advanced_position_unchecked(current_position: (usize, usize), contents_to_be_append: &[RleRun]) -> Result<(usize, usize)> {
+        contents_to_be_append
+            .iter()
+            .try_fold(current_position, |(curr_x, curr_y), RleRun(count, tag)| match tag {
+                RleTag::EndOfLine => match curr_y.checked_add(*count) {
+                    Some(next_y) if next_y < usize::MAX => Ok((0, next_y)),
+                    _ => Err(FormatErrorKind::ExceedsHeight),
+                },
+                _ => curr_x.checked_add(*count).map(|next_x| (next_x, curr_y)).ok_or(FormatErrorKind::ExceedsWidth),
             })
     }
 
@@ -1,7 +1,8 @@
 use anyhow::Result;
 
 use super::{Rle, RleBuilder};
-use crate::{Position, Rule};
+use crate::format::FormatErrorKind;
+use crate::{Board, Format, Position, Rule};
 
 const RULE_HIGHLIFE: Rule = Rule::new(
     &[false, false, false, true, false, false, true, false, false],
@@ -55,9 +56,12 @@ fn do_new_test_to_be_passed(
     Ok(())
 }
 
-fn do_new_test_to_be_failed(pattern: &str) {
+fn do_new_test_to_be_failed<F>(pattern: &str, pred: F)
+where
+    F: Fn(&FormatErrorKind) -> bool,
+{
     let target = Rle::new(pattern.as_bytes());
-    assert!(target.is_err());
+    assert!(matches!(&target, Err(e) if pred(e.kind())));
 }
 
 fn do_from_str_test_to_be_passed(
@@ -94,6 +98,24 @@ fn new_header_with_highlife_rule() -> Result<()> {
     do_new_test_to_be_passed(pattern, 0, 0, &RULE_HIGHLIFE, &Vec::new(), &Vec::new(), true)
 }
 
+#[test]
+fn new_header_with_conways_rule_name() -> Result<()> {
+    let pattern = concat!("x = 0, y = 0, rule = Life\n", "!\n");
+    do_new_test_to_be_passed(pattern, 0, 0, &Rule::conways_life(), &Vec::new(), &Vec::new(), false)
+}
+
+#[test]
+fn new_header_with_highlife_rule_name() -> Result<()> {
+    let pattern = concat!("x = 0, y = 0, rule = HighLife\n", "!\n");
+    do_new_test_to_be_passed(pattern, 0, 0, &RULE_HIGHLIFE, &Vec::new(), &Vec::new(), false)
+}
+
+#[test]
+fn new_header_with_unknown_rule_name() {
+    let pattern = concat!("x = 0, y = 0, rule = Unknown\n", "!\n");
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidRule))
+}
+
 #[test]
 fn new_header_without_rule() -> Result<()> {
     let pattern = concat!("x = 0, y = 0\n", "!\n");
@@ -139,61 +161,133 @@ fn new_comments_header_contents() -> Result<()> {
 #[test]
 fn new_empty() {
     let pattern = "";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::MissingHeader))
 }
 
 #[test]
 fn new_header_invalid_format() {
     let pattern = "_\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_unknown_variable() {
     let pattern = "z = 0\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_invalid_width() {
     let pattern = "x = _, y = 0\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_invalid_height() {
     let pattern = "x = 0, y = _\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_invalid_order() {
     let pattern = "y = 0, x = 0\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_without_width() {
     let pattern = "y = 0\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_without_height() {
     let pattern = "x = 0\n";
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidHeader(_)))
 }
 
 #[test]
 fn new_header_exceed_width() {
     let pattern = concat!("x = 0, y = 1\n", "o!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::ExceedsWidth))
+}
+
+#[test]
+fn new_header_exceed_width_line_number() {
+    let pattern = concat!("x = 2, y = 2\n", "3o$\n", "2o!\n");
+    let target = Rle::new(pattern.as_bytes());
+    assert!(matches!(&target, Err(e) if e.line() == Some(2)));
+    assert!(matches!(&target, Err(e) if e.to_string() == "line 2: The pattern exceeds specified width"));
 }
 
 #[test]
 fn new_header_exceed_height() {
     let pattern = concat!("x = 1, y = 0\n", "o!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::ExceedsHeight))
+}
+
+#[test]
+fn new_large_run_count() -> Result<()> {
+    let pattern = concat!("x = 1000, y = 2, rule = B3/S23\n", "1000o$999bo!\n");
+    do_new_test_to_be_passed(pattern, 1000, 2, &Rule::conways_life(), &Vec::new(), &[(0, 0, 1000), (1, 999, 1)], true)
+}
+
+#[test]
+fn new_run_count_exceeds_usize() {
+    let pattern = concat!("x = 1, y = 1\n", "99999999999999999999999999o!\n");
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidContent))
+}
+
+#[test]
+fn new_run_count_overflows_width() {
+    let pattern = format!("x = {}, y = 1\n{}o{}o!\n", usize::MAX, usize::MAX, usize::MAX);
+    do_new_test_to_be_failed(&pattern, |e| matches!(e, FormatErrorKind::ExceedsWidth))
+}
+
+#[test]
+fn new_lenient_run_count_overflows_width() {
+    let pattern = format!("x = 1, y = 1\n{}b2o!\n", usize::MAX);
+    let target = Rle::new_lenient(pattern.as_bytes());
+    assert!(matches!(&target, Err(e) if matches!(e.kind(), FormatErrorKind::ExceedsWidth)));
+}
+
+#[test]
+fn new_lenient_run_count_overflows_height() {
+    let pattern = format!("x = 1, y = 1\n{}$o!\n", usize::MAX);
+    let target = Rle::new_lenient(pattern.as_bytes());
+    assert!(matches!(&target, Err(e) if matches!(e.kind(), FormatErrorKind::ExceedsHeight)));
+}
+
+#[test]
+fn new_lenient_header_exceed_width() -> Result<()> {
+    let pattern = concat!("x = 0, y = 1\n", "o!\n");
+    let target = Rle::new_lenient(pattern.as_bytes())?;
+    do_check(
+        &target,
+        1,
+        1,
+        &Rule::conways_life(),
+        &Vec::new(),
+        &[(0, 0, 1)],
+        Some(concat!("x = 1, y = 1, rule = B3/S23\n", "o!\n")),
+    );
+    Ok(())
+}
+
+#[test]
+fn new_lenient_header_exceed_height() -> Result<()> {
+    let pattern = concat!("x = 2, y = 1\n", "2o$bo!\n");
+    let target = Rle::new_lenient(pattern.as_bytes())?;
+    do_check(&target, 2, 2, &Rule::conways_life(), &Vec::new(), &[(0, 0, 2), (1, 1, 1)], None);
+    Ok(())
+}
+
+#[test]
+fn new_lenient_header_larger_size() -> Result<()> {
+    let pattern = concat!("x = 3, y = 3\n", "o!\n");
+    let target = Rle::new_lenient(pattern.as_bytes())?;
+    do_check(&target, 3, 3, &Rule::conways_life(), &Vec::new(), &[(0, 0, 1)], None);
+    Ok(())
 }
 
 #[test]
@@ -223,49 +317,49 @@ fn new_content_acceptable_tag_with_count() -> Result<()> {
 #[test]
 fn new_content_alone_count() {
     let pattern = concat!("x = 1, y = 1\n", "1\n", "!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidContent))
 }
 
 #[test]
 fn new_content_count_with_whitespace() {
     let pattern = concat!("x = 1, y = 1\n", "1 \n", "!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidContent))
 }
 
 #[test]
 fn new_content_without_terminator() {
     let pattern = concat!("x = 1, y = 1\n", "o\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::UnterminatedPattern))
 }
 
 #[test]
 fn new_content_terminator_with_count() {
     let pattern = concat!("x = 1, y = 1\n", "2!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::InvalidContent))
 }
 
 #[test]
 fn new_content_exceeds_width_with_dead_cell() {
     let pattern = concat!("x = 1, y = 1\n", "ob!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::ExceedsWidth))
 }
 
 #[test]
 fn new_content_exceeds_width_with_dead_cells() {
     let pattern = concat!("x = 2, y = 2\n", "2o$o2b!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::ExceedsWidth))
 }
 
 #[test]
 fn new_content_exceeds_height_with_end_of_line() {
     let pattern = concat!("x = 1, y = 1\n", "o$!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::ExceedsHeight))
 }
 
 #[test]
 fn new_content_exceeds_height_with_end_of_lines() {
     let pattern = concat!("x = 1, y = 2\n", "o2$!\n");
-    do_new_test_to_be_failed(pattern)
+    do_new_test_to_be_failed(pattern, |e| matches!(e, FormatErrorKind::ExceedsHeight))
 }
 
 #[test]
@@ -342,6 +436,96 @@ fn build() -> Result<()> {
     Ok(())
 }
 
+fn check_minimal_encoding(target: &Rle) {
+    let content: String = target.to_string().lines().skip_while(|line| !line.starts_with("x = ")).skip(1).collect();
+    let mut runs = Vec::new();
+    let mut count = String::new();
+    for c in content.chars() {
+        if c.is_ascii_digit() {
+            count.push(c);
+        } else {
+            runs.push((count.parse::<usize>().ok(), c));
+            count.clear();
+        }
+    }
+    assert!(runs.iter().all(|&(count, _)| count != Some(1)));
+    for pair in runs.windows(2) {
+        let ((_, lhs), (_, rhs)) = (pair[0], pair[1]);
+        assert_ne!(lhs, rhs);
+        assert!(!(lhs == 'b' && (rhs == '$' || rhs == '!')));
+        assert!(!(lhs == '$' && rhs == '!'));
+    }
+}
+
+#[test]
+fn build_minimal_encoding() -> Result<()> {
+    let pattern = [
+        Position(0, 0),
+        Position(1, 0),
+        Position(2, 0),
+        Position(4, 0),
+        Position(3, 3),
+        Position(0, 6),
+        Position(5, 6),
+    ];
+    let target = pattern.iter().collect::<RleBuilder>().build()?;
+    check_minimal_encoding(&target);
+    assert_eq!(target.to_string(), concat!("x = 6, y = 7, rule = B3/S23\n", "3obo3$3bo3$o4bo!\n"));
+    Ok(())
+}
+
+#[test]
+fn build_minimal_encoding_long_runs() -> Result<()> {
+    let pattern = [Position(0, 0), Position(12, 0), Position(0, 21), Position(1, 21)];
+    let target = pattern.iter().collect::<RleBuilder>().build()?;
+    check_minimal_encoding(&target);
+    assert_eq!(target.to_string(), concat!("x = 13, y = 22, rule = B3/S23\n", "o11bo21$2o!\n"));
+    Ok(())
+}
+
+#[test]
+fn display_minimal_encoding_from_nonoptimal() -> Result<()> {
+    let pattern = concat!("x = 4, y = 5\n", "oo1b1b$$1$bbbo$2b!\n");
+    let target = Rle::new(pattern.as_bytes())?;
+    check_minimal_encoding(&target);
+    assert_eq!(target.to_string(), concat!("x = 4, y = 5, rule = B3/S23\n", "2o3$3bo!\n"));
+    Ok(())
+}
+
+#[test]
+fn from_board_normalized_negative_glider() -> Result<()> {
+    let board: Board<i16> = [Position(-9, -20), Position(-8, -19), Position(-10, -18), Position(-9, -18), Position(-8, -18)]
+        .iter()
+        .collect();
+    let (builder, offset) = RleBuilder::from_board_normalized(&board)?;
+    assert_eq!(offset, Position(-10, -20));
+    let target: Rle = builder.build()?.to_string().parse()?;
+    let result: Board<i16> = target
+        .live_cells()
+        .map(|Position(x, y)| Position(x as i16 + offset.0, y as i16 + offset.1))
+        .collect();
+    assert_eq!(result, board);
+    Ok(())
+}
+
+#[test]
+fn from_board_normalized_wide_range() -> Result<()> {
+    let board: Board<i8> = [Position(i8::MIN, 0), Position(i8::MAX, 0)].iter().collect();
+    let (builder, offset) = RleBuilder::from_board_normalized(&board)?;
+    assert_eq!(offset, Position(i8::MIN, 0));
+    let target = builder.build()?;
+    assert_eq!(target.width(), 256);
+    Ok(())
+}
+
+#[test]
+fn from_board_normalized_empty() -> Result<()> {
+    let (builder, offset) = RleBuilder::from_board_normalized(&Board::<i16>::new())?;
+    assert_eq!(offset, Position(0, 0));
+    assert_eq!(builder.build()?.live_cells().count(), 0);
+    Ok(())
+}
+
 #[test]
 fn build_singleline_name() -> Result<()> {
     let pattern = [Position(0, 0)];
@@ -454,8 +638,97 @@ fn display_max_width() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn to_string_rows_long_rows() -> Result<()> {
+    let pattern = ["x = 72, y = 3, rule = B3/S23", &"bo".repeat(35), "bo2$", "o!"]
+        .iter()
+        .map(|&s| s.to_string() + "\n")
+        .collect::<String>();
+    let target = Rle::new(pattern.as_bytes())?;
+    let result = target.to_string_rows();
+    let content_lines: Vec<_> = result.lines().skip_while(|line| !line.starts_with("x = ")).skip(1).collect();
+    assert_eq!(content_lines, vec![format!("{}bo2$", "bo".repeat(35)), "o!".to_string()]);
+    assert!(content_lines.iter().all(|line| line.ends_with('$') || line.ends_with('!')));
+    let reparsed: Rle = result.parse()?;
+    assert!(reparsed.live_cells().eq(target.live_cells()));
+    Ok(())
+}
+
+#[test]
+fn to_string_rows_leading_empty_rows() -> Result<()> {
+    let pattern = concat!("#N comment\n", "x = 2, y = 3, rule = B3/S23\n", "2$2o!\n");
+    let target = Rle::new(pattern.as_bytes())?;
+    assert_eq!(
+        target.to_string_rows(),
+        concat!("#N comment\n", "x = 2, y = 3, rule = B3/S23\n", "2$\n", "2o!\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn display_comments_verbatim() -> Result<()> {
+    let pattern = concat!(
+        "#C comment before name\n",
+        "#N name\n",
+        "\n",
+        "#r B3/S23\n",
+        "#C  double spaced comment \n",
+        "#O author\n",
+        "#unknown\n",
+        "x = 2, y = 2, rule = B3/S23\n",
+        "o$bo!\n"
+    );
+    let target = Rle::new(pattern.as_bytes())?;
+    assert_eq!(target.to_string(), pattern);
+    Ok(())
+}
+
 #[test]
 fn from_str() -> Result<()> {
     let pattern = concat!("#comment0\n", "#comment1\n", "x = 2, y = 2, rule = B3/S23\n", "o$bo!\n");
     do_from_str_test_to_be_passed(pattern, 2, 2, &Rule::conways_life(), &["#comment0", "#comment1"], &[(0, 0, 1), (1, 1, 1)], true)
 }
+
+#[test]
+fn summary() -> Result<()> {
+    let pattern = concat!("#N T-tetromino\n", "#C comment\n", "x = 4, y = 3, rule = B36/S23\n", "$b3o$2bo!\n");
+    let target = Rle::new(pattern.as_bytes())?;
+    let summary = Format::summary(&target);
+    assert_eq!(summary.population(), 4);
+    assert_eq!(summary.bounding_box(), &[Position(1, 1), Position(3, 2)].iter().collect());
+    assert_eq!(summary.rule(), &"B36/S23".parse()?);
+    assert_eq!(summary.name(), Some("T-tetromino"));
+    assert_eq!(summary.comments(), &["#N T-tetromino", "#C comment"]);
+    Ok(())
+}
+
+#[test]
+fn summary_empty() -> Result<()> {
+    let pattern = concat!("x = 0, y = 0, rule = B3/S23\n", "!\n");
+    let target = Rle::new(pattern.as_bytes())?;
+    let summary = Format::summary(&target);
+    assert_eq!(summary.population(), 0);
+    assert!(summary.bounding_box().is_empty());
+    assert_eq!(summary.name(), None);
+    assert!(summary.comments().is_empty());
+    Ok(())
+}
+
+#[test]
+fn new_with_bom() -> Result<()> {
+    let pattern = concat!("#N test\n", "x = 2, y = 2, rule = B3/S23\n", "o$bo!\n");
+    let expected = Rle::new(pattern.as_bytes())?;
+    let target = Rle::new(format!("\u{FEFF}{pattern}").as_bytes())?;
+    assert_eq!(target.comments(), expected.comments());
+    assert!(target.live_cells().eq(expected.live_cells()));
+    assert_eq!(target.to_string(), pattern);
+    Ok(())
+}
+
+#[test]
+fn new_with_bom_before_header() -> Result<()> {
+    let pattern = concat!("x = 2, y = 2, rule = B3/S23\n", "o$bo!\n");
+    let target = Rle::new(format!("\u{FEFF}{pattern}").as_bytes())?;
+    assert_eq!(target.to_string(), pattern);
+    Ok(())
+}
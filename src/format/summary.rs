@@ -0,0 +1,164 @@
+use crate::{BoardRange, Format, Rule};
+
+/// A summary of a pattern, aggregated from a format handler.
+///
+/// This summary is returned by [`Format::summary()`].
+///
+/// [`Format::summary()`]: crate::Format::summary
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Format, Rule};
+/// use life_backend::format::Rle;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pattern = "\
+///     #N T-tetromino\n\
+///     x = 3, y = 2, rule = B3/S23\n\
+///     3o$bo!\n\
+/// ";
+/// let handler: Box<dyn Format> = Box::new(pattern.parse::<Rle>()?);
+/// let summary = handler.summary();
+/// assert_eq!(summary.population(), 4);
+/// assert_eq!(summary.bounding_box().x(), &(0..=2));
+/// assert_eq!(summary.bounding_box().y(), &(0..=1));
+/// assert_eq!(summary.rule(), &Rule::conways_life());
+/// assert_eq!(summary.name(), Some("T-tetromino"));
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PatternSummary {
+    population: usize,
+    bounding_box: BoardRange<usize>,
+    rule: Rule,
+    name: Option<String>,
+    comments: Vec<String>,
+}
+
+// Inherent methods
+
+impl PatternSummary {
+    // Creates a summary from the rule and the live cells of the specified format handler, without any name and comments.
+    pub(super) fn from_format<F>(handler: &F) -> Self
+    where
+        F: Format + ?Sized,
+    {
+        Self {
+            population: handler.live_cells().count(),
+            bounding_box: handler.live_cells().collect(),
+            rule: handler.rule(),
+            name: None,
+            comments: Vec::new(),
+        }
+    }
+
+    // Replaces the name and the comments of the summary.
+    pub(super) fn with_name_and_comments(self, name: Option<String>, comments: Vec<String>) -> Self {
+        Self { name, comments, ..self }
+    }
+
+    /// Returns the number of live cells in the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Format;
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "OOO\n.O.\n";
+    /// let handler = pattern.parse::<Plaintext>()?;
+    /// assert_eq!(handler.summary().population(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn population(&self) -> usize {
+        self.population
+    }
+
+    /// Returns the minimum bounding box of live cells in the pattern.
+    /// If the pattern has no live cells, the returned range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Format;
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "OOO\n.O.\n";
+    /// let handler = pattern.parse::<Plaintext>()?;
+    /// let summary = handler.summary();
+    /// assert_eq!(summary.bounding_box().x(), &(0..=2));
+    /// assert_eq!(summary.bounding_box().y(), &(0..=1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn bounding_box(&self) -> &BoardRange<usize> {
+        &self.bounding_box
+    }
+
+    /// Returns the rule of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Format, Rule};
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "OOO\n.O.\n";
+    /// let handler = pattern.parse::<Plaintext>()?;
+    /// assert_eq!(handler.summary().rule(), &Rule::conways_life());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Returns the name of the pattern, if the format handler provides it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Format;
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "!Name: T-tetromino\nOOO\n.O.\n";
+    /// let handler = pattern.parse::<Plaintext>()?;
+    /// assert_eq!(handler.summary().name(), Some("T-tetromino"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns comments of the pattern, if the format handler provides them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Format;
+    /// use life_backend::format::Plaintext;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pattern = "!Name: T-tetromino\n!comment\nOOO\n.O.\n";
+    /// let handler = pattern.parse::<Plaintext>()?;
+    /// assert_eq!(handler.summary().comments(), &["comment"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+}
@@ -1,14 +1,22 @@
+use anyhow::{anyhow, ensure, Result};
+use fnv::FnvBuildHasher;
+use num_iter::range_inclusive;
 use num_traits::{Bounded, One, ToPrimitive, Zero};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
 use std::hash::Hash;
 use std::mem;
 use std::ops::{Add, Sub};
+use std::str::FromStr;
 
-use crate::{Board, Position, Rule};
+use crate::{apgcode, format, Board, BoardRange, CellStorage, Certificate, PatternKind, Position, Rule, Stepper, Topology};
 
 /// A representation of a game.
 ///
 /// The type parameter `T` is used as the type of the x- and y-coordinate values for each cell.
+/// The type parameter `S` is used as the storage of live cells (see [`CellStorage`]), and it is [`Board<T>`] by default.
 ///
 /// The following operations are supported:
 ///
@@ -16,6 +24,9 @@ use crate::{Board, Position, Rule};
 /// - Advancing a generation
 /// - Returning the current state information
 ///
+/// With the `serde` feature, the game can be serialized and deserialized with the rule, the topology and the current board.
+/// The board of the previous generation is not serialized, and it is reset to an empty board on deserialization.
+///
 /// # Examples
 ///
 /// ```
@@ -34,13 +45,28 @@ use crate::{Board, Position, Rule};
 /// ```
 ///
 #[derive(Clone, PartialEq, Eq, Debug)]
-pub struct Game<T>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game<T, S = Board<T>>
 where
     T: Eq + Hash,
 {
     rule: Rule,
-    curr_board: Board<T>,
-    prev_board: Board<T>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    topology: Topology<T>,
+    #[cfg_attr(feature = "serde", serde(rename = "board"))]
+    curr_board: S,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    prev_board: S,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    peak: Option<PeakPopulation>,
+}
+
+// The peak population tracked by Game, see Game::track_peak_population()
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct PeakPopulation {
+    population: usize,
+    generation: usize,
+    elapsed: usize,
 }
 
 // Inherent methods
@@ -63,8 +89,104 @@ where
     pub fn new(rule: Rule, board: Board<T>) -> Self {
         Self {
             rule,
+            topology: Topology::Infinite,
             curr_board: board,
             prev_board: Board::new(),
+            peak: None,
+        }
+    }
+
+    /// Creates from the specified rule, the board and the topology.
+    ///
+    /// If the topology is [`Topology::Bounded`], live cells outside the region are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, BoardRange, Game, Position, Rule, Topology};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(1, 0), Position(0, 1), Position(5, 5)].iter().collect();
+    /// let region: BoardRange<_> = [Position(0, 0), Position(3, 3)].iter().collect();
+    /// let game = Game::new_with_topology(rule, board, Topology::Bounded(region));
+    /// assert_eq!(game.board().iter().count(), 2);
+    /// ```
+    ///
+    pub fn new_with_topology(rule: Rule, board: Board<T>, topology: Topology<T>) -> Self
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        Self::new_with_storage(rule, board, topology)
+    }
+}
+
+impl<T, S> Game<T, S>
+where
+    T: Eq + Hash,
+{
+    /// Creates from the specified rule, the storage of live cells and the topology.
+    ///
+    /// This method is used to play a game on a storage other than [`Board`], which is used by [`new()`] and [`new_with_topology()`].
+    /// If the topology is [`Topology::Bounded`], live cells outside the region are discarded.
+    ///
+    /// [`new()`]: #method.new
+    /// [`new_with_topology()`]: #method.new_with_topology
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule, Topology};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(1, 0), Position(0, 1)].iter().collect();
+    /// let game: Game<i16, Board<i16>> = Game::new_with_storage(rule, board, Topology::Infinite);
+    /// assert_eq!(game.board().iter().count(), 2);
+    /// ```
+    ///
+    pub fn new_with_storage(rule: Rule, board: S, topology: Topology<T>) -> Self
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+    {
+        let mut game = Self {
+            rule,
+            topology,
+            curr_board: board,
+            prev_board: S::default(),
+            peak: None,
+        };
+        game.clip();
+        game
+    }
+
+    /// Returns a new game with the current board and the topology of the game, and the specified rule.
+    ///
+    /// The history of the game is not inherited, i.e., the returned game starts from the current board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let board: Board<_> = [Position(1, 0), Position(0, 1)].iter().collect();
+    /// let game = Game::new(Rule::conways_life(), board);
+    /// let highlife = "B36/S23".parse::<Rule>()?;
+    /// let other = game.with_rule(highlife.clone());
+    /// assert_eq!(other.rule(), &highlife);
+    /// assert_eq!(other.board(), game.board());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn with_rule(&self, rule: Rule) -> Self
+    where
+        T: Clone,
+        S: Clone + Default,
+    {
+        Self {
+            rule,
+            topology: self.topology.clone(),
+            curr_board: self.curr_board.clone(),
+            prev_board: S::default(),
+            peak: None,
         }
     }
 
@@ -85,6 +207,23 @@ where
         &self.rule
     }
 
+    /// Returns the topology.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule, Topology};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(1, 0), Position(0, 1)].iter().collect();
+    /// let game = Game::new(rule, board);
+    /// assert_eq!(game.topology(), &Topology::Infinite);
+    /// ```
+    ///
+    #[inline]
+    pub const fn topology(&self) -> &Topology<T> {
+        &self.topology
+    }
+
     /// Returns the board.
     ///
     /// # Examples
@@ -105,18 +244,10 @@ where
     /// ```
     ///
     #[inline]
-    pub const fn board(&self) -> &Board<T> {
+    pub const fn board(&self) -> &S {
         &self.curr_board
     }
 
-    // Returns the count of live neighbours of the specified position.
-    fn live_neighbour_count(board: &Board<T>, position: &Position<T>) -> usize
-    where
-        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
-    {
-        position.moore_neighborhood_positions().filter(|pos| board.contains(pos)).count()
-    }
-
     /// Advance the game by one generation.
     ///
     /// # Examples
@@ -137,32 +268,649 @@ where
     /// ```
     ///
     pub fn advance(&mut self)
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+    {
+        mem::swap(&mut self.curr_board, &mut self.prev_board);
+        self.prev_board.step_into(&self.rule, &mut self.curr_board);
+        self.clip();
+        self.update_peak();
+    }
+
+    /// Advance the game by one generation using the specified rule instead of the rule of the game.
+    ///
+    /// The rule of the game is not changed, so this method allows simulations switching rules between generations,
+    /// e.g., alternating two rules each generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// let other: Rule = "B3/S".parse()?;
+    /// game.advance_with_rule(&other);
+    /// let expected: Board<_> = [Position(1, 0), Position(1, 2)].iter().collect();
+    /// assert_eq!(game.board(), &expected);
+    /// assert_eq!(game.rule(), &Rule::conways_life());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn advance_with_rule(&mut self, rule: &Rule)
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+    {
+        mem::swap(&mut self.curr_board, &mut self.prev_board);
+        self.prev_board.step_into(rule, &mut self.curr_board);
+        self.clip();
+        self.update_peak();
+    }
+
+    // Discards the live cells outside the region if the topology is bounded
+    fn clip(&mut self)
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+    {
+        if let Topology::Bounded(region) = &self.topology {
+            self.curr_board.retain(|Position(x, y)| region.x().contains(x) && region.y().contains(y));
+        }
+    }
+
+    /// Starts tracking the peak population, i.e., the maximum number of live cells seen and the generation when it is first seen.
+    ///
+    /// The generation is counted from the call of this method, i.e., the current board is the generation `0`.
+    /// Calling this method again restarts tracking from the current board.
+    /// Tracking counts the live cells in each generation, so it is disabled by default.
+    /// See also [`peak_population()`].
+    ///
+    /// [`peak_population()`]: #method.peak_population
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 0), Position(1, 0), Position(2, 0), Position(1, 1)].iter().collect(); // T-tetromino
+    /// let mut game = Game::new(rule, board);
+    /// game.track_peak_population();
+    /// game.advance_by(2);
+    /// assert_eq!(game.peak_population(), Some((7, 1)));
+    /// ```
+    ///
+    pub fn track_peak_population(&mut self)
+    where
+        T: Copy,
+        S: CellStorage<T>,
+    {
+        self.peak = Some(PeakPopulation {
+            population: self.curr_board.iter().count(),
+            generation: 0,
+            elapsed: 0,
+        });
+    }
+
+    /// Returns the peak population and the generation when it is first seen, as `(population, generation)`.
+    ///
+    /// Returns `None` if tracking is not started by [`track_peak_population()`].
+    ///
+    /// [`track_peak_population()`]: #method.track_peak_population
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// assert_eq!(game.peak_population(), None);
+    /// game.track_peak_population();
+    /// game.advance_by(4);
+    /// assert_eq!(game.peak_population(), Some((3, 0)));
+    /// ```
+    ///
+    #[inline]
+    pub fn peak_population(&self) -> Option<(usize, usize)> {
+        self.peak.map(|peak| (peak.population, peak.generation))
+    }
+
+    // Updates the peak population if tracking is enabled, called after each generation
+    fn update_peak(&mut self)
+    where
+        T: Copy,
+        S: CellStorage<T>,
+    {
+        if let Some(peak) = &mut self.peak {
+            peak.elapsed += 1;
+            let population = self.curr_board.iter().count();
+            if population > peak.population {
+                peak.population = population;
+                peak.generation = peak.elapsed;
+            }
+        }
+    }
+
+    /// Advances the game by the specified number of generations, and returns the number of generations actually advanced.
+    ///
+    /// If the board becomes empty under a rule where an empty board stays empty (see [`Rule::is_stable_background()`]),
+    /// this method stops advancing at that generation, so the returned value is the generation when the pattern died out.
+    /// Otherwise, the returned value is `steps`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 0), Position(1, 0)].iter().collect();
+    /// let mut game = Game::new(rule, board);
+    /// assert_eq!(game.advance_by(10), 1);
+    /// assert_eq!(game.board().iter().count(), 0);
+    /// ```
+    ///
+    pub fn advance_by(&mut self, steps: usize) -> usize
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+    {
+        let stops_at_extinction = self.rule.is_stable_background();
+        for generation in 0..steps {
+            if stops_at_extinction && self.curr_board.iter().next().is_none() {
+                return generation;
+            }
+            self.advance();
+        }
+        steps
+    }
+
+    /// Advances the game by the specified number of generations as [`advance_by()`], but stops when the population exceeds the specified limit.
+    ///
+    /// Returns `Ok(generations)` with the number of generations actually advanced if the population never exceeds `max_population`,
+    /// or `Err(generation)` with the first generation whose population exceeds `max_population`.
+    /// In the latter case, the board is left at that generation; `Err(0)` means that the current board already exceeds the limit.
+    /// This method protects long-running simulations of ever-growing patterns, such as guns, from running out of memory.
+    ///
+    /// [`advance_by()`]: #method.advance_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 0), Position(1, 0), Position(2, 0), Position(1, 1)].iter().collect(); // T-tetromino
+    /// let mut game = Game::new(rule.clone(), board.clone());
+    /// assert_eq!(game.advance_by_limited(1, 10), Ok(1));
+    /// let mut game = Game::new(rule, board);
+    /// assert_eq!(game.advance_by_limited(10, 6), Err(1));
+    /// assert_eq!(game.board().iter().count(), 7);
+    /// ```
+    ///
+    pub fn advance_by_limited(&mut self, steps: usize, max_population: usize) -> Result<usize, usize>
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+    {
+        if self.curr_board.iter().count() > max_population {
+            return Err(0);
+        }
+        let stops_at_extinction = self.rule.is_stable_background();
+        for generation in 0..steps {
+            if stops_at_extinction && self.curr_board.iter().next().is_none() {
+                return Ok(generation);
+            }
+            self.advance();
+            if self.curr_board.iter().count() > max_population {
+                return Err(generation + 1);
+            }
+        }
+        Ok(steps)
+    }
+
+    /// Advances the game by the specified number of generations, calling the specified closure after each generation.
+    ///
+    /// The closure is called as `f(generation, &game)`, where `generation` is the number of generations advanced so far (from `1` to `steps`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// let mut generations = Vec::new();
+    /// game.advance_by_with(3, |generation, game| generations.push((generation, game.board().iter().count())));
+    /// assert_eq!(generations, vec![(1, 3), (2, 3), (3, 3)]);
+    /// ```
+    ///
+    pub fn advance_by_with<F>(&mut self, steps: usize, mut f: F)
+    where
+        T: Copy + PartialOrd,
+        S: CellStorage<T>,
+        F: FnMut(usize, &Self),
+    {
+        for generation in 1..=steps {
+            self.advance();
+            f(generation, self);
+        }
+    }
+}
+
+impl<T> Game<T>
+where
+    T: Eq + Hash,
+{
+    /// Advance the game by one generation, using the specified map as the scratch space for counting the live neighbours of each cell.
+    ///
+    /// The result and the algorithm are the same as [`advance()`], which allocates the map in each generation.
+    /// The contents of `scratch` on entry are ignored, and `scratch` is left empty on return while keeping its allocation,
+    /// so the same map can be passed to every call to avoid allocating it in each generation.
+    ///
+    /// [`advance()`]: #method.advance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// use std::collections::HashMap;
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// let mut scratch = HashMap::default();
+    /// game.advance_with_scratch(&mut scratch);
+    /// assert!(scratch.is_empty());
+    /// let expected: Board<_> = [Position(1, 0), Position(1, 1), Position(1, 2)].iter().collect();
+    /// assert_eq!(game.board(), &expected);
+    /// ```
+    ///
+    pub fn advance_with_scratch(&mut self, scratch: &mut HashMap<Position<T>, (usize, bool), FnvBuildHasher>)
     where
         T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
     {
         mem::swap(&mut self.curr_board, &mut self.prev_board);
-        let prev_board = &self.prev_board;
-        let rule = &self.rule;
-        self.curr_board.clear();
-        self.curr_board.extend(
-            self.prev_board
-                .iter()
-                .flat_map(|pos| pos.moore_neighborhood_positions())
-                .filter(|pos| !prev_board.contains(pos)),
+        self.prev_board.step_into_with_scratch(&self.rule, &mut self.curr_board, scratch);
+        self.clip();
+        self.update_peak();
+    }
+
+    /// Renders the cells in the specified range of the board into a buffer of RGBA bytes.
+    ///
+    /// Each cell is rendered as one pixel, and each pixel consists of four bytes in the order of red, green, blue and alpha.
+    /// The pixels are arranged in row-major order from the cell at the minimum x- and y-coordinate values,
+    /// so the buffer holds `width * height * 4` bytes, where `width` and `height` are the numbers of the x- and y-coordinate values in the range.
+    /// If the range is empty, an empty buffer is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, BoardRange, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(1, 0), Position(0, 1)].iter().collect();
+    /// let game = Game::new(rule, board);
+    /// let range: BoardRange<_> = [Position(0, 0), Position(1, 1)].iter().collect();
+    /// let buf = game.render_rgba(&range, [255, 255, 255, 255], [0, 0, 0, 255]);
+    /// assert_eq!(buf.len(), 2 * 2 * 4);
+    /// assert_eq!(buf[0..4], [0, 0, 0, 255]); // Position(0, 0)
+    /// assert_eq!(buf[4..8], [255, 255, 255, 255]); // Position(1, 0)
+    /// ```
+    ///
+    pub fn render_rgba(&self, range: &BoardRange<T>, live: [u8; 4], dead: [u8; 4]) -> Vec<u8>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + One + ToPrimitive,
+    {
+        let (x_range, y_range) = (range.x(), range.y());
+        range_inclusive(*y_range.start(), *y_range.end())
+            .flat_map(|y| range_inclusive(*x_range.start(), *x_range.end()).map(move |x| Position(x, y)))
+            .flat_map(|pos| if self.curr_board.contains(&pos) { live } else { dead })
+            .collect()
+    }
+
+    /// Advances the game by one generation, and returns the positions of the cells that were born and the cells that died.
+    ///
+    /// The returned value is a tuple `(born, died)`, and each vector is in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// let (born, died) = game.advance_reporting();
+    /// assert_eq!(born.len(), 2);
+    /// assert_eq!(died.len(), 2);
+    /// assert!(born.contains(&Position(1, 0)));
+    /// assert!(died.contains(&Position(0, 1)));
+    /// ```
+    ///
+    pub fn advance_reporting(&mut self) -> (Vec<Position<T>>, Vec<Position<T>>)
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        self.advance();
+        let born = self.curr_board.difference_iter(&self.prev_board).copied().collect();
+        let died = self.prev_board.difference_iter(&self.curr_board).copied().collect();
+        (born, died)
+    }
+
+    /// Advances the game by the specified number of generations, and returns the census of the objects on the resulting board.
+    ///
+    /// The objects are the connected components (see [`Board::connected_components()`]) of the live cells in the current and the next generations,
+    /// so that an oscillator whose phase is split into multiple pieces, such as toad, is treated as a single object.
+    /// Each object is simulated in isolation under the rule of the game, and is classified by its [apgcode](https://conwaylife.com/wiki/Apgcode):
+    /// `"xs"` for still lifes, `"xp"` for oscillators and `"xq"` for spaceships, followed by the population or the period and the canonical encoding of the object.
+    /// Objects that do not return to their initial state within 128 generations are classified as `"zz_UNKNOWN"`.
+    /// The returned map holds the number of the objects for each apgcode.
+    ///
+    /// Since objects close to each other are treated as a single object, the census is accurate only if the board has settled into separated objects.
+    /// If the topology is [`Topology::Bounded`], each object is also simulated within the region, so objects touching the boundary may be classified differently from the infinite plane.
+    ///
+    /// [`Board::connected_components()`]: crate::Board::connected_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 0), Position(1, 0), Position(0, 1), Position(1, 1), Position(5, 0), Position(5, 1), Position(5, 2)]
+    ///     .iter()
+    ///     .collect(); // Block and blinker
+    /// let mut game = Game::new(rule, board);
+    /// let census = game.census(0);
+    /// assert_eq!(census.len(), 2);
+    /// assert_eq!(census["xs4_33"], 1);
+    /// assert_eq!(census["xp2_7"], 1);
+    /// ```
+    ///
+    pub fn census(&mut self, settle_steps: usize) -> HashMap<String, usize>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Zero + One + Bounded + ToPrimitive,
+    {
+        for _ in 0..settle_steps {
+            self.advance();
+        }
+        // The objects are separated by the envelope of the current and the next generations,
+        // since some oscillators, such as toad, have a phase consisting of multiple connected components
+        let mut next_board = self.curr_board.step(&self.rule);
+        if let Topology::Bounded(region) = &self.topology {
+            next_board.retain(|&Position(x, y)| region.x().contains(&x) && region.y().contains(&y));
+        }
+        let envelope: Board<T> = self.curr_board.iter().chain(next_board.iter()).collect();
+        envelope
+            .connected_components()
+            .iter()
+            .map(|component| component.iter().filter(|pos| self.curr_board.contains(pos)).collect::<Board<_>>())
+            .filter(|object| object.iter().next().is_some())
+            .fold(HashMap::new(), |mut acc, object| {
+                *acc.entry(Self::classify(&self.rule, &self.topology, &object)).or_insert(0) += 1;
+                acc
+            })
+    }
+
+    // Returns the apgcode of the specified object simulated in isolation under the specified rule and topology, used by census()
+    fn classify(rule: &Rule, topology: &Topology<T>, object: &Board<T>) -> String
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Zero + One + Bounded + ToPrimitive,
+    {
+        const MAX_PERIOD: usize = 128;
+        let mut game = Self::new_with_topology(rule.clone(), object.clone(), topology.clone());
+        let Ok(certificate) = game.classify_with_certificate(MAX_PERIOD) else {
+            return "zz_UNKNOWN".to_owned();
+        };
+        let mut phases = certificate.phases().iter();
+        let first = phases.next().unwrap().canonical_wechsler(); // this unwrap never panic because the phases of a certificate are never empty
+        let best = phases.fold(first, |best, board| apgcode::better(best, board.canonical_wechsler()));
+        match certificate.kind() {
+            PatternKind::StillLife => format!("xs{}_{best}", object.iter().count()),
+            PatternKind::Oscillator => format!("xp{}_{best}", certificate.period()),
+            PatternKind::Spaceship => format!("xq{}_{best}", certificate.period()),
+        }
+    }
+
+    /// Advances the game by the specified number of generations, and returns the boards of all generations.
+    ///
+    /// The returned vector contains `steps + 1` boards: the first one is the board before advancing and the last one is the board after advancing.
+    /// Since all boards are cloned into the vector, the memory usage grows in proportion to `steps` and the population of each generation.
+    /// This method is intended for short runs, such as visualizing or debugging frame by frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board.clone());
+    /// let frames = game.record(2);
+    /// assert_eq!(frames.len(), 3);
+    /// assert_eq!(frames[0], board);
+    /// assert_ne!(frames[1], board);
+    /// assert_eq!(frames[2], board);
+    /// assert_eq!(game.board(), &board);
+    /// ```
+    ///
+    pub fn record(&mut self, steps: usize) -> Vec<Board<T>>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        let mut buf = Vec::with_capacity(steps.saturating_add(1));
+        buf.push(self.curr_board.clone());
+        for _ in 0..steps {
+            self.advance();
+            buf.push(self.curr_board.clone());
+        }
+        buf
+    }
+
+    /// Advances the game by the specified number of generations, and returns the boards of all generations as strings in RLE format.
+    ///
+    /// The returned vector contains `steps + 1` strings, in the same way as [`record()`].
+    /// Each frame is normalized before converted into RLE format, i.e., translated so that the minimum x- and y-coordinate values of its live cells are zero,
+    /// so the absolute position of the board is lost, and a moving pattern such as a glider appears at the same place in all frames of the same phase.
+    ///
+    /// [`record()`]: #method.record
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board);
+    /// let frames = game.render_frames_rle(1);
+    /// assert_eq!(frames.len(), 2);
+    /// assert_eq!(frames[0], "x = 3, y = 1, rule = B3/S23\n3o!\n");
+    /// assert_eq!(frames[1], "x = 1, y = 3, rule = B3/S23\no$o$o!\n");
+    /// ```
+    ///
+    pub fn render_frames_rle(&mut self, steps: usize) -> Vec<String>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Zero + One + Bounded + ToPrimitive,
+    {
+        let mut buf = Vec::with_capacity(steps.saturating_add(1));
+        buf.push(self.frame_rle());
+        for _ in 0..steps {
+            self.advance();
+            buf.push(self.frame_rle());
+        }
+        buf
+    }
+
+    // Returns the string in RLE format of the normalized current board, used by render_frames_rle()
+    fn frame_rle(&self) -> String
+    where
+        T: Copy + PartialOrd + Zero + One + ToPrimitive,
+    {
+        let bbox = self.curr_board.bounding_box();
+        let (min_x, min_y) = (bbox.x().start().to_i128().unwrap(), bbox.y().start().to_i128().unwrap()); // these unwraps never panic because integer types used as T are representable in i128
+        let cells: Vec<_> = self
+            .curr_board
+            .iter()
+            .map(|&Position(x, y)| {
+                let x = (x.to_i128().unwrap() - min_x).to_usize().unwrap(); // this unwrap never panic because x is not less than min_x
+                let y = (y.to_i128().unwrap() - min_y).to_usize().unwrap(); // same as above
+                Position(x, y)
+            })
+            .collect();
+        cells
+            .iter()
+            .collect::<format::RleBuilder>()
+            .rule(self.rule.clone())
+            .build()
+            .unwrap() // this unwrap never panic because no name and no comment are specified
+            .to_string()
+    }
+
+    /// Advances the game by the specified period, and returns the boards of all phases of the oscillator (generations `0..period`).
+    ///
+    /// Returns an error if `period` is zero, or if the board does not return to the initial state after exactly `period` generations,
+    /// i.e., the board does not return after `period` generations or returns before that.
+    /// The game is advanced by `period` generations regardless of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board.clone());
+    /// let phases = game.phases(2)?;
+    /// assert_eq!(phases.len(), 2);
+    /// assert_eq!(phases[0], board);
+    /// assert!(game.phases(3).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn phases(&mut self, period: usize) -> Result<Vec<Board<T>>>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+    {
+        ensure!(period > 0, "The period must be greater than zero");
+        let mut buf = self.record(period);
+        let init = &buf[0];
+        ensure!(
+            buf[period] == *init,
+            "The board does not return to the initial state after {period} generations"
         );
-        self.curr_board.retain(|pos| {
-            let count = Self::live_neighbour_count(prev_board, pos);
-            rule.is_born(count)
-        });
-        self.curr_board.extend(self.prev_board.iter().copied().filter(|pos| {
-            let count = Self::live_neighbour_count(prev_board, pos);
-            rule.is_survive(count)
-        }));
+        ensure!(
+            buf[1..period].iter().all(|board| board != init),
+            "The board returns to the initial state before {period} generations"
+        );
+        buf.pop();
+        Ok(buf)
+    }
+
+    /// Returns the velocity of the pattern on the board as `(dx, dy, period)`, i.e., the pattern moves by `(dx, dy)` every `period` generations.
+    ///
+    /// The period is the smallest one within `max_period` where the board returns to a translation of the current board.
+    /// For example, the glider moving toward the lower right returns `(1, 1, 4)`, i.e., its speed is c/4.
+    /// Still lifes and oscillators return `(0, 0, period)`.
+    /// Returns `None` if the board is empty, or if no such period is found within `max_period`.
+    ///
+    /// The game itself is not advanced, i.e., this method simulates a clone of the game.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<_> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] // Glider pattern
+    ///     .iter()
+    ///     .copied()
+    ///     .map(|(x, y)| Position(x, y))
+    ///     .collect();
+    /// let game = Game::new(rule, board.clone());
+    /// assert_eq!(game.velocity(10), Some((1, 1, 4)));
+    /// assert_eq!(game.board(), &board);
+    /// ```
+    ///
+    pub fn velocity(&self, max_period: usize) -> Option<(i64, i64, usize)>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Zero + One + Bounded + ToPrimitive,
+    {
+        let mut game = self.clone();
+        let certificate = game.classify_with_certificate(max_period).ok()?;
+        let (dx, dy) = certificate.displacement();
+        Some((dx, dy, certificate.period()))
+    }
+
+    /// Advances the game until the board returns to a translation of the initial state, and returns the certificate of the periodic pattern.
+    ///
+    /// The period is the smallest one within `max_period`, and the certificate records the kind of the pattern
+    /// (still life, oscillator or spaceship), the period, the displacement per period and the boards of all phases.
+    /// Returns an error if the board is empty, or if no such period is found within `max_period`.
+    /// On success, the game is advanced by the period, otherwise by `max_period` generations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, PatternKind, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] // Glider pattern
+    ///     .iter()
+    ///     .copied()
+    ///     .map(|(x, y)| Position(x, y))
+    ///     .collect();
+    /// let mut game = Game::new(rule, board);
+    /// let certificate = game.classify_with_certificate(10)?;
+    /// assert_eq!(certificate.kind(), PatternKind::Spaceship);
+    /// assert_eq!(certificate.period(), 4);
+    /// assert_eq!(certificate.displacement(), (1, 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn classify_with_certificate(&mut self, max_period: usize) -> Result<Certificate<T>>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Zero + One + Bounded + ToPrimitive,
+    {
+        let origin = Position(T::zero(), T::zero());
+        let init_bbox = self.curr_board.bounding_box();
+        ensure!(!init_bbox.is_empty(), "The board is empty");
+        let normalized = self.curr_board.translate_to(origin);
+        let mut phases = vec![self.curr_board.clone()];
+        for _ in 0..max_period {
+            self.advance();
+            if self.curr_board.translate_to(origin) == normalized {
+                let bbox = self.curr_board.bounding_box();
+                let to_i64 = |value: &T| value.to_i64().ok_or_else(|| anyhow!("The coordinate cannot be converted into i64"));
+                let dx = to_i64(bbox.x().start())? - to_i64(init_bbox.x().start())?;
+                let dy = to_i64(bbox.y().start())? - to_i64(init_bbox.y().start())?;
+                return Ok(Certificate::new((dx, dy), phases));
+            }
+            phases.push(self.curr_board.clone());
+        }
+        Err(anyhow!(
+            "The board does not return to a translation of the initial state within {max_period} generations"
+        ))
     }
 }
 
 // Trait implementations
 
+impl<T> Stepper<T> for Game<T>
+where
+    T: Eq + Hash + Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + One + Bounded + ToPrimitive,
+{
+    #[inline]
+    fn board(&self) -> &Board<T> {
+        self.board()
+    }
+    #[inline]
+    fn advance(&mut self) {
+        self.advance();
+    }
+    #[inline]
+    fn advance_by(&mut self, steps: usize) -> usize {
+        self.advance_by(steps)
+    }
+}
+
 impl<T> fmt::Display for Game<T>
 where
     T: Eq + Hash + Copy + PartialOrd + Zero + One + ToPrimitive,
@@ -173,11 +921,45 @@ where
     }
 }
 
+impl<T> FromStr for Game<T>
+where
+    T: Eq + Hash + TryFrom<usize>,
+    T::Error: Error + Send + Sync + 'static,
+{
+    type Err = anyhow::Error;
+
+    /// Parses a string in Plaintext or RLE format into a game.
+    /// The format is detected from the content of the string.
+    ///
+    /// Returns an error if the string cannot be parsed, or if the position of a live cell cannot be converted into `Position<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Game, Position, Rule};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let game: Game<i16> = "x = 3, y = 3\nbo$2bo$3o!".parse()?;
+    /// assert_eq!(game.rule(), &Rule::conways_life());
+    /// assert_eq!(game.board().iter().count(), 5);
+    /// assert_eq!(game.board().contains(&Position(1, 0)), true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let handler = format::parse_str(s)?;
+        let rule = handler.rule();
+        let board = format::to_board(handler.as_ref())?;
+        Ok(Self::new(rule, board))
+    }
+}
+
 // Unit tests
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeSet;
     #[test]
     fn display() {
         let rule = Rule::conways_life();
@@ -185,4 +967,467 @@ mod tests {
         let target = Game::new(rule, board);
         println!("{target}");
     }
+    #[test]
+    fn render_rgba() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+        let target = Game::new(rule, board);
+        let range: BoardRange<i16> = [Position(-1, 0), Position(3, 2)].iter().collect();
+        let (live, dead) = ([255, 128, 0, 255], [0, 0, 0, 0]);
+        let result = target.render_rgba(&range, live, dead);
+        let (width, height) = (5, 3);
+        assert_eq!(result.len(), width * height * 4);
+        let offset = (width + 2) * 4; // Position(1, 1)
+        assert_eq!(result[offset..(offset + 4)], live);
+        assert_eq!(result[0..4], dead);
+        assert!(target.render_rgba(&BoardRange::new(), live, dead).is_empty());
+    }
+    #[test]
+    fn advance_by_limited_acorn() -> Result<()> {
+        let handler = format::open("patterns/acorn.rle")?;
+        let board: Board<i16> = format::to_board(handler.as_ref())?;
+        let max_population = 100;
+        let mut target = Game::new(handler.rule(), board.clone());
+        let result = target.advance_by_limited(5000, max_population);
+        let Err(generation) = result else {
+            panic!("the population of acorn must exceed the limit");
+        };
+        assert!(target.board().iter().count() > max_population);
+        let mut expected = Game::new(handler.rule(), board);
+        expected.advance_by(generation - 1);
+        assert!(expected.board().iter().count() <= max_population);
+        expected.advance();
+        assert_eq!(expected.board(), target.board());
+        Ok(())
+    }
+    #[test]
+    fn advance_by_limited_within_limit() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+        let mut target = Game::new(rule.clone(), board.clone());
+        assert_eq!(target.advance_by_limited(10, 3), Ok(10));
+        assert_eq!(target.board(), &board);
+        let mut target = Game::new(rule, board);
+        assert_eq!(target.advance_by_limited(10, 2), Err(0));
+    }
+    #[test]
+    fn peak_population_rpentomino() -> Result<()> {
+        let handler = format::open("patterns/rpentomino.rle")?;
+        let board: Board<i16> = format::to_board(handler.as_ref())?;
+        let mut target = Game::new(handler.rule(), board);
+        target.track_peak_population();
+        assert_eq!(target.peak_population(), Some((5, 0)));
+        let mut expected = (5, 0);
+        for generation in 1..=1200 {
+            target.advance();
+            let population = target.board().iter().count();
+            if population > expected.0 {
+                expected = (population, generation);
+            }
+        }
+        assert_eq!(target.peak_population(), Some(expected));
+        assert_eq!(target.peak_population(), Some((319, 821)));
+        Ok(())
+    }
+    #[test]
+    fn advance_with_scratch_same_as_advance() -> Result<()> {
+        let rule = Rule::conways_life();
+        let board = Board::<i16>::from_rle("#N R-pentomino\nx = 3, y = 3\nb2o$2ob$bo!\n")?;
+        let mut target = Game::new(rule.clone(), board.clone());
+        let mut expected = Game::new(rule, board);
+        let mut scratch = HashMap::default();
+        for _ in 0..200 {
+            target.advance_with_scratch(&mut scratch);
+            expected.advance();
+            assert_eq!(target.board(), expected.board());
+            assert!(scratch.is_empty());
+        }
+        assert!(scratch.capacity() > 0);
+        Ok(())
+    }
+    #[test]
+    fn advance_reporting_blinker() {
+        let rule = Rule::conways_life();
+        let horizontal: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        let vertical: Board<i16> = [Position(1, 0), Position(1, 1), Position(1, 2)].iter().collect();
+        let mut target = Game::new(rule, horizontal.clone());
+        let (born, died) = target.advance_reporting();
+        assert_eq!(born.iter().collect::<Board<_>>(), [Position(1, 0), Position(1, 2)].iter().collect());
+        assert_eq!(died.iter().collect::<Board<_>>(), [Position(0, 1), Position(2, 1)].iter().collect());
+        assert_eq!(target.board(), &vertical);
+        let (born, died) = target.advance_reporting();
+        assert_eq!(born.iter().collect::<Board<_>>(), [Position(0, 1), Position(2, 1)].iter().collect());
+        assert_eq!(died.iter().collect::<Board<_>>(), [Position(1, 0), Position(1, 2)].iter().collect());
+        assert_eq!(target.board(), &horizontal);
+    }
+    #[test]
+    fn census_bheptomino() -> Result<()> {
+        let handler = format::open("patterns/bheptomino.rle")?;
+        let board = format::to_board::<i16>(handler.as_ref())?;
+        let mut target = Game::new(handler.rule(), board);
+        let result = target.census(148);
+        let expected: HashMap<_, _> = [("xs4_33", 3), ("xs6_356", 1), ("xq4_153", 2)]
+            .iter()
+            .map(|&(code, count)| (code.to_owned(), count))
+            .collect();
+        assert_eq!(result, expected);
+        Ok(())
+    }
+    #[test]
+    fn census_oscillators() {
+        let rule = Rule::conways_life();
+        let toad: Board<i16> = [Position(1, 0), Position(2, 0), Position(3, 0), Position(0, 1), Position(1, 1), Position(2, 1)]
+            .iter()
+            .collect();
+        let mut target = Game::new(rule, toad);
+        let result = target.census(1);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result["xp2_7e"], 1);
+    }
+    #[test]
+    fn census_bounded() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [
+            Position(0, 0),
+            Position(1, 0),
+            Position(2, 0),
+            Position(6, 1),
+            Position(7, 1),
+            Position(6, 2),
+            Position(7, 2),
+        ]
+        .iter()
+        .collect(); // Blinker on the boundary and block
+        let region: BoardRange<i16> = [Position(0, 0), Position(7, 2)].iter().collect();
+        let mut target = Game::new_with_topology(rule.clone(), board.clone(), Topology::Bounded(region));
+        let result = target.census(0);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result["xs4_33"], 1);
+        assert_eq!(result["zz_UNKNOWN"], 1);
+        let mut target = Game::new(rule, board);
+        let result = target.census(0);
+        assert_eq!(result["xp2_7"], 1);
+    }
+    #[test]
+    fn advance_bounded_glider_absorbed() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let region: BoardRange<i16> = [Position(0, 0), Position(29, 9)].iter().collect();
+        let mut target = Game::new_with_topology(rule.clone(), board.clone(), Topology::Bounded(region.clone()));
+        let mut infinite = Game::new(rule, board);
+        for _ in 0..50 {
+            target.advance();
+            infinite.advance();
+            assert!(target.board().iter().all(|Position(x, y)| region.x().contains(x) && region.y().contains(y)));
+        }
+        // The glider hitting the bottom edge is absorbed, leaving a block at the edge
+        let expected: Board<i16> = [Position(8, 8), Position(9, 8), Position(8, 9), Position(9, 9)].iter().collect();
+        assert_eq!(target.board(), &expected);
+        target.advance();
+        assert_eq!(target.board(), &expected);
+        assert_eq!(infinite.board().iter().count(), 5);
+        assert!(infinite.board().iter().any(|Position(_, y)| *y > 9));
+    }
+    #[test]
+    fn new_with_topology_clip() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(-1, 0), Position(0, 0), Position(1, 0)].iter().collect();
+        let region: BoardRange<i16> = [Position(0, 0), Position(4, 4)].iter().collect();
+        let target = Game::new_with_topology(rule, board, Topology::Bounded(region.clone()));
+        let expected: Board<i16> = [Position(0, 0), Position(1, 0)].iter().collect();
+        assert_eq!(target.board(), &expected);
+        assert_eq!(target.topology(), &Topology::Bounded(region));
+    }
+    #[test]
+    fn advance_by_with_population() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 0), Position(0, 1), Position(1, 1), Position(1, 2)]
+            .iter()
+            .collect(); // R-pentomino pattern
+        let mut target = Game::new(rule, board);
+        let mut expected_game = target.clone();
+        let mut result = Vec::new();
+        target.advance_by_with(10, |generation, game| result.push((generation, game.board().iter().count())));
+        let expected: Vec<_> = (1..=10)
+            .map(|generation| {
+                expected_game.advance();
+                (generation, expected_game.board().iter().count())
+            })
+            .collect();
+        assert_eq!(result, expected);
+        assert_eq!(target, expected_game);
+    }
+    #[test]
+    fn render_frames_rle_glider() -> Result<()> {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .map(|&Position(x, y)| Position(x - 5, y + 3))
+            .collect(); // Glider pattern, placed apart from the origin
+        let mut target = Game::new(rule, board.clone());
+        let result = target.render_frames_rle(4);
+        assert_eq!(result.len(), 5);
+        let frame0 = Board::<i16>::from_rle(&result[0])?;
+        assert_eq!(frame0, board.translate_to(Position(0, 0)));
+        assert_eq!(result[4], result[0]);
+        assert_ne!(result[1], result[0]);
+        Ok(())
+    }
+    #[test]
+    fn record_glider() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let mut target = Game::new(rule, board);
+        let result = target.record(4);
+        assert_eq!(result.len(), 5);
+        let expected: Board<_> = result[0].iter().map(|&Position(x, y)| Position(x + 1, y + 1)).collect();
+        assert_eq!(result[4], expected);
+        assert_eq!(target.board(), &expected);
+    }
+    #[test]
+    fn phases_blinker() -> anyhow::Result<()> {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        let mut target = Game::new(rule, board.clone());
+        let result = target.phases(2)?;
+        let expected: Board<i16> = [Position(1, 0), Position(1, 1), Position(1, 2)].iter().collect();
+        assert_eq!(result, vec![board.clone(), expected]);
+        assert_eq!(target.board(), &board);
+        Ok(())
+    }
+    #[test]
+    fn phases_wrong_period() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        let mut target = Game::new(rule, board);
+        assert!(target.phases(0).is_err());
+        assert!(target.phases(3).is_err());
+        assert!(target.phases(4).is_err());
+    }
+    #[test]
+    fn advance_with_rule_alternating() -> anyhow::Result<()> {
+        let rules: [Rule; 2] = ["B3/S23".parse()?, "B36/S125".parse()?];
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let init_rule: Rule = "B36/S23".parse()?;
+        let mut target = Game::new(init_rule.clone(), board.clone());
+        let mut expected = board;
+        for i in 0..20 {
+            let rule = &rules[i % 2];
+            target.advance_with_rule(rule);
+            expected = expected.step(rule);
+            assert_eq!(target.board(), &expected);
+        }
+        assert_eq!(target.rule(), &init_rule);
+        Ok(())
+    }
+    #[test]
+    fn classify_with_certificate_blinker() -> anyhow::Result<()> {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        let mut target = Game::new(rule, board.clone());
+        let result = target.classify_with_certificate(10)?;
+        let expected: Board<i16> = [Position(1, 0), Position(1, 1), Position(1, 2)].iter().collect();
+        assert_eq!(result.kind(), PatternKind::Oscillator);
+        assert_eq!(result.period(), 2);
+        assert_eq!(result.displacement(), (0, 0));
+        assert_eq!(result.phases(), &[board.clone(), expected]);
+        assert_eq!(target.board(), &board);
+        Ok(())
+    }
+    #[test]
+    fn classify_with_certificate_glider() -> anyhow::Result<()> {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        let mut target = Game::new(rule, board.clone());
+        let result = target.classify_with_certificate(10)?;
+        let expected: Board<i16> = board.iter().map(|&Position(x, y)| Position(x + 1, y + 1)).collect();
+        assert_eq!(result.kind(), PatternKind::Spaceship);
+        assert_eq!(result.period(), 4);
+        assert_eq!(result.displacement(), (1, 1));
+        assert_eq!(result.phases().len(), 4);
+        assert_eq!(result.phases()[0], board);
+        assert_eq!(target.board(), &expected);
+        Ok(())
+    }
+    #[test]
+    fn classify_with_certificate_failure() {
+        let rule = Rule::conways_life();
+        let mut target = Game::new(rule.clone(), Board::<i16>::new());
+        assert!(target.classify_with_certificate(10).is_err());
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect();
+        let mut target = Game::new(rule, board);
+        assert!(target.classify_with_certificate(1).is_err());
+    }
+    #[test]
+    fn from_str_glider_rle() -> anyhow::Result<()> {
+        let mut target: Game<i16> = "x = 3, y = 3\nbo$2bo$3o!".parse()?;
+        let expected: Board<_> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect();
+        assert_eq!(target.board(), &expected);
+        for _ in 0..4 {
+            target.advance();
+        }
+        let expected: Board<_> = expected.iter().map(|&Position(x, y)| Position(x + 1, y + 1)).collect();
+        assert_eq!(target.board(), &expected);
+        Ok(())
+    }
+    #[test]
+    fn from_str_plaintext() -> anyhow::Result<()> {
+        let target: Game<i16> = "!Name: Blinker\nOOO\n".parse()?;
+        assert_eq!(target.rule(), &Rule::conways_life());
+        assert_eq!(target.board().iter().count(), 3);
+        Ok(())
+    }
+    #[test]
+    fn from_str_coordinate_overflow() {
+        let target = "x = 200, y = 1\n199bo!".parse::<Game<i8>>();
+        assert!(target.is_err());
+    }
+    #[test]
+    fn advance_by_blinker() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+        let mut target = Game::new(rule, board.clone());
+        assert_eq!(target.advance_by(4), 4);
+        assert_eq!(target.board(), &board);
+    }
+    #[test]
+    fn advance_by_unstable_background() -> Result<()> {
+        let rule = "B0/S".parse::<Rule>()?;
+        let mut target = Game::new(rule, Board::<i16>::new());
+        assert_eq!(target.advance_by(3), 3);
+        Ok(())
+    }
+    #[test]
+    fn stepper_advance_by() -> Result<()> {
+        let handler = format::open("patterns/rpentomino.rle")?;
+        let board = format::to_board::<i16>(handler.as_ref())?;
+        let mut target = Game::new(handler.rule(), board.clone());
+        let mut expected = Game::new(handler.rule(), board);
+        assert_eq!(Stepper::advance_by(&mut target, 8), 8);
+        for _ in 0..8 {
+            Stepper::advance(&mut expected);
+        }
+        assert_eq!(Stepper::board(&target), Stepper::board(&expected));
+        Ok(())
+    }
+    // A storage of live cells for testing, which keeps live cells in a sorted set
+    #[derive(Clone, Default, Debug)]
+    struct SortedStorage(BTreeSet<(i16, i16)>);
+    impl CellStorage<i16> for SortedStorage {
+        fn contains(&self, position: &Position<i16>) -> bool {
+            self.0.contains(&(position.0, position.1))
+        }
+        fn insert(&mut self, position: Position<i16>) -> bool {
+            self.0.insert((position.0, position.1))
+        }
+        fn iter(&self) -> Box<dyn Iterator<Item = Position<i16>> + '_> {
+            Box::new(self.0.iter().map(|&(x, y)| Position(x, y)))
+        }
+        fn clear(&mut self) {
+            self.0.clear();
+        }
+        fn moore_neighbors(&self, position: &Position<i16>) -> Box<dyn Iterator<Item = Position<i16>> + '_> {
+            Box::new(position.moore_neighborhood_positions())
+        }
+    }
+    #[test]
+    fn storage_blinker() {
+        let rule = Rule::conways_life();
+        let pattern = [Position(0, 1), Position(1, 1), Position(2, 1)]; // Blinker pattern
+        let mut storage = SortedStorage::default();
+        for &pos in &pattern {
+            storage.insert(pos);
+        }
+        let mut target: Game<i16, SortedStorage> = Game::new_with_storage(rule.clone(), storage, Topology::Infinite);
+        let mut expected = Game::new(rule, pattern.iter().collect());
+        for _ in 0..3 {
+            target.advance();
+            expected.advance();
+            let result: Board<i16> = target.board().iter().collect();
+            assert_eq!(&result, expected.board());
+        }
+        assert_eq!(target.board().0, [(1, 0), (1, 1), (1, 2)].iter().copied().collect());
+    }
+    #[test]
+    fn storage_bounded() {
+        let rule = Rule::conways_life();
+        let mut storage = SortedStorage::default();
+        for pos in [Position(0, 1), Position(1, 1), Position(2, 1), Position(5, 5)] {
+            storage.insert(pos);
+        }
+        let region: BoardRange<i16> = [Position(0, 0), Position(2, 2)].iter().collect();
+        let mut target = Game::new_with_storage(rule, storage, Topology::Bounded(region));
+        assert_eq!(target.board().0.len(), 3);
+        assert_eq!(target.advance_by(2), 2);
+        assert_eq!(target.board().0, [(0, 1), (1, 1), (2, 1)].iter().copied().collect());
+    }
+    #[test]
+    fn velocity_glider() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect(); // Glider pattern
+        let target = Game::new(rule, board.clone());
+        assert_eq!(target.velocity(10), Some((1, 1, 4)));
+        assert_eq!(target.velocity(3), None);
+        assert_eq!(target.board(), &board);
+    }
+    #[test]
+    fn velocity_lwss() -> Result<()> {
+        let handler = format::open("patterns/lwss.rle")?;
+        let board = format::to_board::<i16>(handler.as_ref())?;
+        let target = Game::new(handler.rule(), board);
+        assert_eq!(target.velocity(10), Some((-2, 0, 4)));
+        Ok(())
+    }
+    #[test]
+    fn velocity_oscillator_and_empty() {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+        assert_eq!(Game::new(rule.clone(), board).velocity(10), Some((0, 0, 2)));
+        assert_eq!(Game::new(rule, Board::<i16>::new()).velocity(10), None);
+    }
+    #[test]
+    fn with_rule_highlife() -> Result<()> {
+        // The center cell has six live neighbours, so it is born only in HighLife
+        let board: Board<i16> = [(0, 0), (1, 0), (2, 0), (0, 2), (1, 2), (2, 2)].iter().map(|&(x, y)| Position(x, y)).collect();
+        let mut conways_life = Game::new(Rule::conways_life(), board);
+        let mut highlife = conways_life.with_rule("B36/S23".parse::<Rule>()?);
+        assert_eq!(highlife.board(), conways_life.board());
+        conways_life.advance();
+        highlife.advance();
+        assert!(!conways_life.board().contains(&Position(1, 1)));
+        assert!(highlife.board().contains(&Position(1, 1)));
+        Ok(())
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() -> Result<()> {
+        let rule = Rule::conways_life();
+        let board: Board<i16> = [Position(1, 0), Position(2, 1), Position(0, 2), Position(1, 2), Position(2, 2)]
+            .iter()
+            .collect(); // Glider pattern
+        let region: BoardRange<i16> = [Position(-10, -10), Position(10, 10)].iter().collect();
+        let mut target = Game::new_with_topology(rule, board, Topology::Bounded(region));
+        target.advance();
+        let serialized = serde_json::to_string(&target)?;
+        let mut deserialized: Game<i16> = serde_json::from_str(&serialized)?;
+        assert_eq!(deserialized.rule(), target.rule());
+        assert_eq!(deserialized.topology(), target.topology());
+        assert_eq!(deserialized.board(), target.board());
+        target.advance();
+        deserialized.advance();
+        assert_eq!(deserialized.board(), target.board());
+        Ok(())
+    }
 }
@@ -6,7 +6,7 @@
 //! The following operations are supported:
 //!
 //! - Parsing or writing patterns of Life-like cellular automata
-//!   (Plaintext and RLE formats are supported)
+//!   (Plaintext, RLE, Life 1.06 and coordinate-list formats are supported)
 //! - Parsing or writing rules in the birth/survival notation (e.g., `"B3/S23"`)
 //! - Managing a board, a two-dimensional orthogonal grid map of live and dead cells
 //!   (The type of the x- and y-coordinates of positions is generalized)
@@ -69,23 +69,49 @@
 //! .OO
 //! .O.
 //! ```
+//!
+//! # Crate features
+//!
+//! - `serde`: Enables serialization and deserialization of [`Rule`], [`Position`], [`BoardRange`], [`Board`],
+//!   [`Topology`] and [`Game`] with [serde](https://serde.rs/).
+//!   A rule is represented as the string in the birth/survival notation, e.g., `"B3/S23"`.
 
 // Lint settings for documentation
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
 mod rule;
-pub use rule::Rule;
+pub use rule::{ParseRuleError, ParseRuleErrorKind, Rule};
 
 mod position;
 pub use position::Position;
 
+mod d4;
+pub use d4::D4;
+
 mod boardrange;
 pub use boardrange::BoardRange;
 
 mod board;
 pub use board::Board;
 
+mod stateboard;
+pub use stateboard::StateBoard;
+
+mod apgcode;
+
+mod topology;
+pub use topology::Topology;
+
+mod storage;
+pub use storage::CellStorage;
+
+mod stepper;
+pub use stepper::Stepper;
+
+mod certificate;
+pub use certificate::{Certificate, PatternKind};
+
 mod game;
 pub use game::Game;
 
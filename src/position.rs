@@ -1,9 +1,9 @@
 use num_iter::range_inclusive;
-use num_traits::{Bounded, One, ToPrimitive};
+use num_traits::{Bounded, CheckedMul, One, ToPrimitive, Zero};
 use std::convert::TryFrom;
 use std::fmt;
 use std::hash::Hash;
-use std::ops::{Add, Sub};
+use std::ops::{Add, Mul, Sub};
 
 /// A position of a cell.
 ///
@@ -23,6 +23,7 @@ use std::ops::{Add, Sub};
 /// ```
 ///
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position<T>(pub T, pub T);
 
 impl<T> Position<T> {
@@ -110,6 +111,192 @@ impl<T> Position<T> {
             .flat_map(move |v| range_inclusive(x_start, x_stop).map(move |u| Position(u, v)))
             .filter(move |&pos| pos != Position(x, y))
     }
+
+    /// Creates an owning iterator over neighbour positions of the self position on a torus of the specified width and height.
+    ///
+    /// The neighbour positions are defined in [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood),
+    /// and the coordinate values wrap around the edges of the grid, e.g., the left neighbour of `Position(0, y)` is `Position(width - 1, y)`.
+    /// This method is intended for unsigned coordinate types, and the self position must be inside the grid, i.e., `0 <= x < width` and `0 <= y < height`.
+    ///
+    /// The iterator always yields eight positions in row-major order.
+    /// If `width` or `height` is less than 3, the same position may be yielded multiple times, and the self position itself may be yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `width` or `height` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use life_backend::Position;
+    /// let pos: Position<u8> = Position(0, 0);
+    /// let result: HashSet<_> = pos
+    ///     .moore_neighbors_wrapping(4, 3)
+    ///     .collect();
+    /// let expected: HashSet<_> = [(3, 2), (0, 2), (1, 2), (3, 0), (1, 0), (3, 1), (0, 1), (1, 1)]
+    ///     .iter()
+    ///     .copied()
+    ///     .map(|(x, y)| Position(x, y))
+    ///     .collect();
+    /// assert_eq!(result, expected);
+    /// ```
+    ///
+    pub fn moore_neighbors_wrapping(&self, width: T, height: T) -> impl Iterator<Item = Self>
+    where
+        T: Copy + PartialEq + Add<Output = T> + Sub<Output = T> + Zero + One,
+    {
+        fn wrapping_triple<T>(v: T, len: T) -> [T; 3]
+        where
+            T: Copy + PartialEq + Add<Output = T> + Sub<Output = T> + Zero + One,
+        {
+            let one = T::one();
+            let prev = if v == T::zero() { len - one } else { v - one };
+            let next = if v + one == len { T::zero() } else { v + one };
+            [prev, v, next]
+        }
+        let xs = wrapping_triple(self.0, width);
+        let ys = wrapping_triple(self.1, height);
+        (0..9).filter(|&i| i != 4).map(move |i| Position(xs[i % 3], ys[i / 3]))
+    }
+
+    /// Returns the position transformed by the specified affine transformation, i.e., `m * self + offset`.
+    ///
+    /// The matrix `m` is in row-major order, so the transformed position is
+    /// `Position(m[0][0] * x + m[0][1] * y + offset.0, m[1][0] * x + m[1][1] * y + offset.1)`.
+    /// Rotations, reflections and translations, including the eight symmetries of the square, are expressed by this method.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if an intermediate or resulting coordinate value overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Position;
+    /// let pos = Position(2, 3);
+    /// let rotated = pos.transform([[0, -1], [1, 0]], Position(0, 0)); // 90 degrees clockwise rotation, the y-axis points down
+    /// assert_eq!(rotated, Position(-3, 2));
+    /// let flipped = pos.transform([[-1, 0], [0, 1]], Position(10, 0)); // horizontal flip, then translation
+    /// assert_eq!(flipped, Position(8, 3));
+    /// ```
+    ///
+    pub fn transform(&self, m: [[T; 2]; 2], offset: Position<T>) -> Self
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        let Position(x, y) = *self;
+        Position(m[0][0] * x + m[0][1] * y + offset.0, m[1][0] * x + m[1][1] * y + offset.1)
+    }
+
+    /// Returns the position scaled by the specified factor, i.e., both coordinate values are multiplied by `factor`.
+    ///
+    /// Combined with [`Board::map_positions()`], this method zooms a pattern up, e.g., before rendering.
+    /// See also [`checked_scale()`], which does not panic on overflow.
+    ///
+    /// [`Board::map_positions()`]: crate::Board::map_positions
+    /// [`checked_scale()`]: #method.checked_scale
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if a resulting coordinate value overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Position;
+    /// let pos = Position(2, -3);
+    /// assert_eq!(pos.scale(3), Position(6, -9));
+    /// ```
+    ///
+    pub fn scale(&self, factor: T) -> Self
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        let Position(x, y) = *self;
+        Position(x * factor, y * factor)
+    }
+
+    /// Returns the position scaled by the specified factor, or `None` if a resulting coordinate value overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Position;
+    /// let pos: Position<i8> = Position(2, 50);
+    /// assert_eq!(pos.checked_scale(2), Some(Position(4, 100)));
+    /// assert_eq!(pos.checked_scale(3), None);
+    /// ```
+    ///
+    pub fn checked_scale(&self, factor: T) -> Option<Self>
+    where
+        T: CheckedMul,
+    {
+        let Position(x, y) = self;
+        Some(Position(x.checked_mul(&factor)?, y.checked_mul(&factor)?))
+    }
+
+    /// Returns `true` if the specified position is a neighbour of the self position in [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood),
+    /// i.e., the Chebyshev distance between them is 1.
+    /// The self position itself is not a neighbour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Position;
+    /// let pos = Position(2, 3);
+    /// assert!(pos.is_moore_neighbor(&Position(3, 4)));
+    /// assert!(pos.is_moore_neighbor(&Position(2, 2)));
+    /// assert!(!pos.is_moore_neighbor(&Position(2, 3)));
+    /// assert!(!pos.is_moore_neighbor(&Position(4, 3)));
+    /// ```
+    ///
+    pub fn is_moore_neighbor(&self, other: &Self) -> bool
+    where
+        T: Copy + PartialOrd + Add<Output = T> + One,
+    {
+        matches!(self.axis_distances(other), Some((dx, dy)) if dx + dy > 0)
+    }
+
+    /// Returns `true` if the specified position is a neighbour of the self position in [von Neumann neighbourhood](https://conwaylife.com/wiki/Von_Neumann_neighbourhood),
+    /// i.e., the Manhattan distance between them is 1.
+    /// The self position itself is not a neighbour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Position;
+    /// let pos = Position(2, 3);
+    /// assert!(pos.is_von_neumann_neighbor(&Position(2, 2)));
+    /// assert!(!pos.is_von_neumann_neighbor(&Position(3, 4)));
+    /// assert!(!pos.is_von_neumann_neighbor(&Position(2, 3)));
+    /// ```
+    ///
+    pub fn is_von_neumann_neighbor(&self, other: &Self) -> bool
+    where
+        T: Copy + PartialOrd + Add<Output = T> + One,
+    {
+        matches!(self.axis_distances(other), Some((dx, dy)) if dx + dy == 1)
+    }
+
+    // Returns the distances between the positions along the x- and y-axes, or None if either of them is greater than 1.
+    // This never overflows, unlike subtracting the coordinate values.
+    fn axis_distances(&self, other: &Self) -> Option<(usize, usize)>
+    where
+        T: Copy + PartialOrd + Add<Output = T> + One,
+    {
+        fn distance<T>(a: T, b: T) -> Option<usize>
+        where
+            T: Copy + PartialOrd + Add<Output = T> + One,
+        {
+            if a == b {
+                return Some(0);
+            }
+            let (lower, upper) = if a < b { (a, b) } else { (b, a) };
+            (lower + T::one() == upper).then_some(1)
+        }
+        Some((distance(self.0, other.0)?, distance(self.1, other.1)?))
+    }
 }
 
 impl<T> fmt::Display for Position<T>
@@ -182,9 +369,65 @@ mod tests {
         );
     }
     #[test]
+    fn moore_neighbors_wrapping_corners() {
+        let (width, height): (u32, u32) = (3, 3);
+        let all: HashSet<Position<u32>> = (0..height).flat_map(|y| (0..width).map(move |x| Position(x, y))).collect();
+        for pos in [Position(0, 0), Position(2, 0), Position(0, 2), Position(2, 2)].iter() {
+            let result: Vec<_> = pos.moore_neighbors_wrapping(width, height).collect();
+            assert_eq!(result.len(), 8);
+            let result: HashSet<_> = result.into_iter().collect();
+            let expected: HashSet<_> = all.iter().copied().filter(|p| p != pos).collect();
+            assert_eq!(result, expected);
+        }
+        let result: Vec<_> = Position(0u32, 0u32).moore_neighbors_wrapping(width, height).collect();
+        assert_eq!(result[0], Position(2, 2));
+        assert_eq!(result[3], Position(2, 0));
+    }
+    #[test]
+    fn moore_neighbors_wrapping_max() {
+        let max = u8::MAX;
+        let result: HashSet<_> = Position(max - 1, 0).moore_neighbors_wrapping(max, 1).collect();
+        let expected: HashSet<_> = [Position(max - 2, 0), Position(max - 1, 0), Position(0, 0)].iter().copied().collect();
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn transform_rotate_90() {
+        let rotate: [[I; 2]; 2] = [[0, -1], [1, 0]];
+        let origin = Position(0, 0);
+        let pos = Position(2, 1);
+        let result: Vec<_> = (0..4)
+            .scan(pos, |curr, _| {
+                *curr = curr.transform(rotate, origin);
+                Some(*curr)
+            })
+            .collect();
+        assert_eq!(result, vec![Position(-1, 2), Position(-2, -1), Position(1, -2), Position(2, 1)]);
+    }
+    #[test]
+    fn scale() {
+        let target = Position(2, 3);
+        assert_eq!(target.scale(3), Position(6, 9));
+        assert_eq!(target.scale(0), Position(0, 0));
+        assert_eq!(target.scale(-1), Position(-2, -3));
+    }
+    #[test]
+    fn checked_scale_overflow() {
+        let target: Position<i8> = Position(64, 1);
+        assert_eq!(target.checked_scale(1), Some(target));
+        assert_eq!(target.checked_scale(2), None);
+        assert_eq!(Position(1u8, 200).checked_scale(2), None);
+    }
+    #[test]
+    fn transform_flip_horizontal() {
+        let flip: [[I; 2]; 2] = [[-1, 0], [0, 1]];
+        assert_eq!(Position(2, 1).transform(flip, Position(0, 0)), Position(-2, 1));
+        assert_eq!(Position(2, 1).transform(flip, Position(4, 0)), Position(2, 1));
+        assert_eq!(Position(0, 5).transform(flip, Position(0, 0)), Position(0, 5));
+    }
+    #[test]
     fn moore_neighborhood_positions_bounds() {
-        let min = I::min_value();
-        let max = I::max_value();
+        let min = I::MIN;
+        let max = I::MAX;
         let zero: I = 0;
         for (pos_tuple, expected_count) in [
             ((min, min), 3),
@@ -201,4 +444,29 @@ mod tests {
             assert_eq!(pos.moore_neighborhood_positions().count(), expected_count);
         }
     }
+    #[test]
+    fn is_neighbor_pairs() {
+        let pos: Position<I> = Position(0, 0);
+        for (other, expected_moore, expected_von_neumann) in [
+            (Position(1, 1), true, false),
+            (Position(-1, 1), true, false),
+            (Position(0, 1), true, true),
+            (Position(-1, 0), true, true),
+            (Position(0, 0), false, false),
+            (Position(2, 0), false, false),
+            (Position(2, 1), false, false),
+        ] {
+            assert_eq!(pos.is_moore_neighbor(&other), expected_moore);
+            assert_eq!(pos.is_von_neumann_neighbor(&other), expected_von_neumann);
+            assert_eq!(other.is_moore_neighbor(&pos), expected_moore);
+            assert_eq!(other.is_von_neumann_neighbor(&pos), expected_von_neumann);
+        }
+    }
+    #[test]
+    fn is_neighbor_bounds() {
+        let (min, max) = (I::MIN, I::MAX);
+        assert!(Position(min, min).is_moore_neighbor(&Position(min + 1, min + 1)));
+        assert!(Position(max, 0).is_von_neumann_neighbor(&Position(max - 1, 0)));
+        assert!(!Position(min, 0).is_moore_neighbor(&Position(max, 0)));
+    }
 }
@@ -4,21 +4,80 @@ use std::str::FromStr;
 
 const TRUTH_TABLE_SIZE: usize = 9;
 
+// Converts the truth table into the string of numbers, e.g., "23"
+fn convert_slice_to_string(slice: &[bool]) -> String {
+    slice
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &x)| if x { Some(i) } else { None })
+        .map(|n| char::from_digit(n as u32, TRUTH_TABLE_SIZE as u32).unwrap()) // this unwrap never panic because `n < TRUTH_TABLE_SIZE` is always guaranteed
+        .collect()
+}
+
+// The maximum length of the string in the birth/survival notation, i.e., the length of "B012345678/S012345678"
+const RULE_STRING_CAPACITY: usize = 2 * TRUTH_TABLE_SIZE + 3;
+
+// Converts the pair of truth tables into the string in the birth/survival notation, e.g., "B3/S23", held in a fixed-size buffer with its length
+const fn convert_slices_to_rule_string(birth: &[bool; TRUTH_TABLE_SIZE], survival: &[bool; TRUTH_TABLE_SIZE]) -> ([u8; RULE_STRING_CAPACITY], usize) {
+    let mut buf = [0; RULE_STRING_CAPACITY];
+    let mut len = 0;
+    buf[len] = b'B';
+    len += 1;
+    let mut i = 0;
+    while i < TRUTH_TABLE_SIZE {
+        if birth[i] {
+            buf[len] = b'0' + i as u8;
+            len += 1;
+        }
+        i += 1;
+    }
+    buf[len] = b'/';
+    buf[len + 1] = b'S';
+    len += 2;
+    let mut i = 0;
+    while i < TRUTH_TABLE_SIZE {
+        if survival[i] {
+            buf[len] = b'0' + i as u8;
+            len += 1;
+        }
+        i += 1;
+    }
+    (buf, len)
+}
+
+// Converts the truth table into the bit mask, where bit `i` is the value for `i`
+const fn convert_slice_to_mask(slice: &[bool; TRUTH_TABLE_SIZE]) -> u16 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < TRUTH_TABLE_SIZE {
+        if slice[i] {
+            mask |= 1 << i;
+        }
+        i += 1;
+    }
+    mask
+}
+
 /// A representation of a rule of [Life-like cellular automata](https://conwaylife.com/wiki/Life-like_cellular_automaton).
 ///
 /// The following operations are supported:
 ///
 /// - Constructing from a pair of truth tables
 /// - Parsing a string into a value of this type, e.g., `"B3/S23"`.
+///   Whitespaces in the string are ignored (e.g., `"B3 / S23"`), and duplicate numbers are allowed (e.g., `"B33/S223"`).
 ///   The following notations are supported, see [Rulestring](https://conwaylife.com/wiki/Rulestring):
-///   - The birth/survival notation (e.g., `"B3/S23"`). Lowercase `'b'` or `'s'` are also allowed in the notation instead of `'B'` or `'S'`
+///   - The birth/survival notation (e.g., `"B3/S23"`). Lowercase `'b'` or `'s'` are also allowed in the notation instead of `'B'` or `'S'`,
+///     and the survival field may precede the birth field (e.g., `"S23/B3"`)
 ///   - S/B notation (e.g., `"23/3"`)
+/// - Looking up a well-known rule by its name, e.g., `"HighLife"`
 /// - Determining whether a new cell will be born from the specified number of alive neighbors
 /// - Determining whether a cell surrounded by the specified number of alive neighbors will survive
 /// - Converting into a [`String`] value, e.g., `"B3/S23"`.
-///   This operation only supports the birth/survival notation
+///   This operation supports the birth/survival notation via [`Display`], and S/B notation via [`to_s_b_string()`]
 ///
 /// [`String`]: std::string::String
+/// [`Display`]: std::fmt::Display
+/// [`to_s_b_string()`]: #method.to_s_b_string
 ///
 /// # Examples
 ///
@@ -35,10 +94,13 @@ const TRUTH_TABLE_SIZE: usize = 9;
 /// # }
 /// ```
 ///
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct Rule {
     birth: [bool; TRUTH_TABLE_SIZE],
     survival: [bool; TRUTH_TABLE_SIZE],
+    // The string in the birth/survival notation, precomputed at construction for as_str()
+    string_buf: [u8; RULE_STRING_CAPACITY],
+    string_len: usize,
 }
 
 // Inherent methods
@@ -63,10 +125,59 @@ impl Rule {
     /// ```
     ///
     pub const fn new(birth: &[bool; 9], survival: &[bool; 9]) -> Self {
+        let (string_buf, string_len) = convert_slices_to_rule_string(birth, survival);
         Self {
             birth: *birth,
             survival: *survival,
+            string_buf,
+            string_len,
+        }
+    }
+
+    /// Creates a new rule based on the specified predicate.
+    ///
+    /// The predicate is called as `f(alive, count)` for each state of the cell (`alive` is `false` or `true`)
+    /// and each number of alive neighbors (`count` is from 0 to 8),
+    /// and it returns whether the cell will be alive in the next generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::from_fn(|alive, count| count % 2 == 0 && (alive || count > 0)); // Survive on even counts, born on positive even counts
+    /// assert_eq!(rule.to_string(), "B2468/S02468");
+    /// ```
+    ///
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(bool, usize) -> bool,
+    {
+        let mut birth = [false; TRUTH_TABLE_SIZE];
+        let mut survival = [false; TRUTH_TABLE_SIZE];
+        for count in 0..TRUTH_TABLE_SIZE {
+            birth[count] = f(false, count);
+            survival[count] = f(true, count);
         }
+        Self::new(&birth, &survival)
+    }
+
+    /// Returns the string of the rule in the birth/survival notation, e.g., `"B3/S23"`.
+    ///
+    /// The string is the same as the one written by [`Display`], but it is precomputed when the rule is constructed,
+    /// so this method does not allocate or format anything.
+    ///
+    /// [`Display`]: std::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// assert_eq!(rule.as_str(), "B3/S23");
+    /// ```
+    ///
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.string_buf[..self.string_len]).unwrap() // this unwrap never panic because the buffer only includes ASCII characters
     }
 
     /// Returns whether a new cell will be born from the specified number of alive neighbors.
@@ -113,6 +224,429 @@ impl Rule {
         self.survival[count]
     }
 
+    /// Returns whether a new cell will be born from the specified number of alive neighbors, or `None` if the argument `count` is greater than 8.
+    ///
+    /// This is the non-panicking version of [`is_born()`].
+    ///
+    /// [`is_born()`]: #method.is_born
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// assert_eq!(rule.is_born_checked(3), Some(true));
+    /// assert_eq!(rule.is_born_checked(4), Some(false));
+    /// assert_eq!(rule.is_born_checked(9), None);
+    /// ```
+    ///
+    #[inline]
+    pub const fn is_born_checked(&self, count: usize) -> Option<bool> {
+        if count < TRUTH_TABLE_SIZE {
+            Some(self.birth[count])
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether a cell surrounded by the specified number of alive neighbors will survive, or `None` if the argument `count` is greater than 8.
+    ///
+    /// This is the non-panicking version of [`is_survive()`].
+    ///
+    /// [`is_survive()`]: #method.is_survive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// assert_eq!(rule.is_survive_checked(2), Some(true));
+    /// assert_eq!(rule.is_survive_checked(4), Some(false));
+    /// assert_eq!(rule.is_survive_checked(9), None);
+    /// ```
+    ///
+    #[inline]
+    pub const fn is_survive_checked(&self, count: usize) -> Option<bool> {
+        if count < TRUTH_TABLE_SIZE {
+            Some(self.survival[count])
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the infinite dead background is stable under the rule, i.e., a dead cell without live neighbors stays dead.
+    ///
+    /// Rules containing `B0` make every dead cell far from the pattern become alive, so they cannot be simulated correctly on the infinite board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert!(Rule::conways_life().is_stable_background());
+    /// assert!(!"B03/S23".parse::<Rule>()?.is_stable_background());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub const fn is_stable_background(&self) -> bool {
+        !self.is_born(0)
+    }
+
+    /// Returns the bit mask of the birth condition, where bit `i` is set if a new cell will be born from `i` alive neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// let mask = rule.birth_mask();
+    /// assert_eq!((mask >> 3) & 1, 1);
+    /// assert_eq!((mask >> 2) & 1, 0);
+    /// ```
+    ///
+    #[inline]
+    pub const fn birth_mask(&self) -> u16 {
+        convert_slice_to_mask(&self.birth)
+    }
+
+    /// Returns the bit mask of the survival condition, where bit `i` is set if a cell surrounded by `i` alive neighbors will survive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// let mask = rule.survival_mask();
+    /// assert_eq!((mask >> 2) & 1, 1);
+    /// assert_eq!((mask >> 4) & 1, 0);
+    /// ```
+    ///
+    #[inline]
+    pub const fn survival_mask(&self) -> u16 {
+        convert_slice_to_mask(&self.survival)
+    }
+
+    /// Creates an owning iterator over the numbers of alive neighbors from which a new cell will be born, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule: Rule = "B36/S23".parse()?;
+    /// assert!(rule.birth_counts().eq([3, 6]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn birth_counts(&self) -> impl Iterator<Item = usize> {
+        let birth = self.birth;
+        (0..birth.len()).filter(move |&i| birth[i])
+    }
+
+    /// Creates an owning iterator over the numbers of alive neighbors with which a cell will survive, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// assert!(rule.survival_counts().eq([2, 3]));
+    /// ```
+    ///
+    pub fn survival_counts(&self) -> impl Iterator<Item = usize> {
+        let survival = self.survival;
+        (0..survival.len()).filter(move |&i| survival[i])
+    }
+
+    /// Returns the minimum number of alive neighbors from which a new cell will be born, or `None` if there is no such number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Rule::conways_life().min_birth_neighbors(), Some(3));
+    /// assert_eq!("B/S23".parse::<Rule>()?.min_birth_neighbors(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn min_birth_neighbors(&self) -> Option<usize> {
+        self.birth.iter().position(|&b| b)
+    }
+
+    /// Returns the maximum number of alive neighbors from which a new cell will be born, or `None` if there is no such number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Rule::conways_life().max_birth_neighbors(), Some(3));
+    /// assert_eq!("B/S23".parse::<Rule>()?.max_birth_neighbors(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn max_birth_neighbors(&self) -> Option<usize> {
+        self.birth.iter().rposition(|&b| b)
+    }
+
+    /// Returns the minimum number of alive neighbors with which a cell will survive, or `None` if there is no such number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Rule::conways_life().min_survival_neighbors(), Some(2));
+    /// assert_eq!("B3/S".parse::<Rule>()?.min_survival_neighbors(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn min_survival_neighbors(&self) -> Option<usize> {
+        self.survival.iter().position(|&b| b)
+    }
+
+    /// Returns the maximum number of alive neighbors with which a cell will survive, or `None` if there is no such number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Rule::conways_life().max_survival_neighbors(), Some(3));
+    /// assert_eq!("B3/S".parse::<Rule>()?.max_survival_neighbors(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    #[inline]
+    pub fn max_survival_neighbors(&self) -> Option<usize> {
+        self.survival.iter().rposition(|&b| b)
+    }
+
+    /// Returns the transition table of the rule, expanded to all 512 configurations of a cell and its eight neighbors.
+    ///
+    /// Each index of the table is a 9-bit value representing a configuration of the 3x3 cells, in the same order as the MAP rule format:
+    /// the cells are arranged in row-major order from the north-west cell to the south-east cell, the north-west cell is the most significant bit,
+    /// and the center cell is the bit 4 (i.e., `0x10`).
+    /// Each value of the table is the state of the center cell in the next generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// let table = rule.to_transition_table();
+    /// assert_eq!(table[0b000_010_000], false); // an isolated live cell dies
+    /// assert_eq!(table[0b110_010_000], true); // a live cell with two live neighbors survives
+    /// assert_eq!(table[0b111_000_000], true); // a new cell is born from three live neighbors
+    /// ```
+    ///
+    pub fn to_transition_table(&self) -> [bool; 512] {
+        const CENTER_MASK: usize = 0x10;
+        let mut table = [false; 512];
+        for (index, state) in table.iter_mut().enumerate() {
+            let count = (index & !CENTER_MASK).count_ones() as usize;
+            *state = if index & CENTER_MASK != 0 { self.survival[count] } else { self.birth[count] };
+        }
+        table
+    }
+
+    /// Returns the transitions where the self rule and the specified rule disagree.
+    ///
+    /// Each item of the returned vector is a tuple `(is_birth, count)`.
+    /// `is_birth` is `true` for a birth transition and `false` for a survival transition, and `count` is the number of alive neighbors.
+    /// Birth transitions come first, and each group of transitions is sorted in ascending order of `count`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let conways_life = Rule::conways_life();
+    /// let highlife = "B36/S23".parse::<Rule>()?;
+    /// assert_eq!(conways_life.transition_diff(&highlife), vec![(true, 6)]);
+    /// assert!(conways_life.transition_diff(&conways_life).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn transition_diff(&self, other: &Self) -> Vec<(bool, usize)> {
+        [(true, &self.birth, &other.birth), (false, &self.survival, &other.survival)]
+            .iter()
+            .flat_map(|&(is_birth, lhs, rhs)| {
+                lhs.iter()
+                    .zip(rhs.iter())
+                    .enumerate()
+                    .filter(|(_, (l, r))| l != r)
+                    .map(move |(count, _)| (is_birth, count))
+            })
+            .collect()
+    }
+
+    /// Returns the number of transitions where the self rule and the specified rule disagree, i.e., the Hamming distance between both rules.
+    ///
+    /// The returned value is in the range of `0..=18`, and is the same as the length of the vector returned by [`transition_diff()`].
+    ///
+    /// [`transition_diff()`]: #method.transition_diff
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let conways_life = Rule::conways_life();
+    /// let highlife = "B36/S23".parse::<Rule>()?;
+    /// assert_eq!(conways_life.hamming_distance(&highlife), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        self.birth
+            .iter()
+            .chain(self.survival.iter())
+            .zip(other.birth.iter().chain(other.survival.iter()))
+            .filter(|(l, r)| l != r)
+            .count()
+    }
+
+    /// Returns the number of active transitions of the rule, i.e., the number of the birth counts plus the number of the survival counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// assert_eq!(rule.transition_count(), 3);
+    /// ```
+    ///
+    pub fn transition_count(&self) -> usize {
+        self.birth.iter().chain(self.survival.iter()).filter(|&&x| x).count()
+    }
+
+    /// Creates an owning iterator over all rules with exactly the specified numbers of the birth counts and the survival counts.
+    ///
+    /// Each of the birth counts and the survival counts is chosen from the nine possible counts (`0..=8`),
+    /// so the iterator yields `C(9, birth_count) * C(9, survival_count)` rules, where `C(n, k)` is the binomial coefficient.
+    /// For example, `enumerate(4, 4)` yields `126 * 126 = 15876` rules, which is one of the largest cases,
+    /// and all combinations of the arguments yield `2^18 = 262144` rules in total.
+    /// The iterator yields nothing if either of the arguments is greater than 9.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// assert_eq!(Rule::enumerate(1, 2).count(), 9 * 36);
+    /// assert!(Rule::enumerate(1, 2).any(|rule| rule == Rule::conways_life()));
+    /// assert!(Rule::enumerate(1, 2).all(|rule| rule.transition_count() == 3));
+    /// ```
+    ///
+    pub fn enumerate(birth_count: usize, survival_count: usize) -> impl Iterator<Item = Self> {
+        // Creates an owning iterator over all truth tables with exactly the specified number of true values
+        fn slices_with_count(count: usize) -> impl Iterator<Item = [bool; TRUTH_TABLE_SIZE]> + Clone {
+            (0..(1_u16 << TRUTH_TABLE_SIZE))
+                .filter(move |mask| mask.count_ones() as usize == count)
+                .map(|mask| {
+                    let mut slice = [false; TRUTH_TABLE_SIZE];
+                    for (i, x) in slice.iter_mut().enumerate() {
+                        *x = (mask >> i) & 1 == 1;
+                    }
+                    slice
+                })
+        }
+        let survivals = slices_with_count(survival_count);
+        slices_with_count(birth_count).flat_map(move |birth| survivals.clone().map(move |survival| Self::new(&birth, &survival)))
+    }
+
+    /// Converts the rule into a [`String`] value in S/B notation, e.g., `"23/3"`.
+    ///
+    /// [`String`]: std::string::String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rule = Rule::conways_life();
+    /// let s = rule.to_s_b_string();
+    /// assert_eq!(s, "23/3");
+    /// assert_eq!(s.parse::<Rule>()?, rule);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn to_s_b_string(&self) -> String {
+        let mut buf = convert_slice_to_string(&self.survival);
+        buf += "/";
+        buf += &convert_slice_to_string(&self.birth);
+        buf
+    }
+
+    /// Returns the rule corresponding to the specified well-known rule name, or `None` if the name is unknown.
+    ///
+    /// Names are matched case-insensitively, ignoring whitespace, `'-'`, `'_'` and `'\''`.
+    /// The following names are supported:
+    ///
+    /// | Name | Rule |
+    /// | --- | --- |
+    /// | `"Life"`, `"Conway"`, `"Conway's Life"`, `"Game of Life"` | `B3/S23` |
+    /// | `"HighLife"` | `B36/S23` |
+    /// | `"Seeds"` | `B2/S` |
+    /// | `"Day & Night"`, `"DayAndNight"` | `B3678/S34678` |
+    /// | `"34 Life"` | `B34/S34` |
+    /// | `"2x2"` | `B36/S125` |
+    /// | `"Life without Death"` | `B3/S012345678` |
+    /// | `"Maze"` | `B3/S12345` |
+    /// | `"Replicator"` | `B1357/S1357` |
+    /// | `"Morley"` | `B368/S245` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// assert_eq!(Rule::from_name("Life"), Some(Rule::conways_life()));
+    /// assert_eq!(Rule::from_name("highlife").map(|rule| rule.to_string()), Some("B36/S23".to_string()));
+    /// assert_eq!(Rule::from_name("unknown"), None);
+    /// ```
+    ///
+    pub fn from_name(name: &str) -> Option<Self> {
+        const NAMES: [(&str, &str); 14] = [
+            ("life", "B3/S23"),
+            ("conway", "B3/S23"),
+            ("conwayslife", "B3/S23"),
+            ("gameoflife", "B3/S23"),
+            ("highlife", "B36/S23"),
+            ("seeds", "B2/S"),
+            ("day&night", "B3678/S34678"),
+            ("dayandnight", "B3678/S34678"),
+            ("34life", "B34/S34"),
+            ("2x2", "B36/S125"),
+            ("lifewithoutdeath", "B3/S012345678"),
+            ("maze", "B3/S12345"),
+            ("replicator", "B1357/S1357"),
+            ("morley", "B368/S245"),
+        ];
+        let normalized: String = name
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_' | '\''))
+            .flat_map(char::to_lowercase)
+            .collect();
+        NAMES
+            .iter()
+            .find(|(key, _)| *key == normalized)
+            .map(|(_, rulestring)| rulestring.parse().unwrap()) // this unwrap never panic because all rulestrings in NAMES are valid
+    }
+
     /// Returns the rule of [Conway's Game of Life](https://conwaylife.com/wiki/Conway%27s_Game_of_Life).
     ///
     /// # Examples
@@ -138,77 +672,180 @@ impl Rule {
 
 // Trait implementations
 
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Rule").field("birth", &self.birth).field("survival", &self.survival).finish()
+    }
+}
+
 impl fmt::Display for Rule {
+    /// Formats the rule in the birth/survival notation, e.g., `"B3/S23"`.
+    ///
+    /// With the alternate flag (`{:#}`), the rule is formatted in the expanded form listing the numbers of alive neighbors,
+    /// e.g., `"Birth: {3}, Survival: {2, 3}"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::Rule;
+    /// let rule = Rule::conways_life();
+    /// assert_eq!(format!("{rule}"), "B3/S23");
+    /// assert_eq!(format!("{rule:#}"), "Birth: {3}, Survival: {2, 3}");
+    /// ```
+    ///
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fn count_slice_numbers(slice: &[bool]) -> usize {
-            slice.iter().filter(|x| **x).count()
-        }
-        fn convert_slice_to_string(slice: &[bool]) -> String {
-            slice
-                .iter()
-                .enumerate()
-                .filter_map(|(i, &x)| if x { Some(i) } else { None })
-                .map(|n| char::from_digit(n as u32, TRUTH_TABLE_SIZE as u32).unwrap()) // this unwrap never panic because `n < TRUTH_TABLE_SIZE` is always guaranteed
-                .collect()
+        if f.alternate() {
+            let join = |counts: &mut dyn Iterator<Item = usize>| counts.map(|n| n.to_string()).collect::<Vec<_>>().join(", ");
+            write!(
+                f,
+                "Birth: {{{}}}, Survival: {{{}}}",
+                join(&mut self.birth_counts()),
+                join(&mut self.survival_counts())
+            )
+        } else {
+            f.write_str(self.as_str())
         }
-        let mut buf = String::with_capacity(count_slice_numbers(&self.birth) + count_slice_numbers(&self.survival));
-        buf += "B";
-        buf += &convert_slice_to_string(&self.birth);
-        buf += "/S";
-        buf += &convert_slice_to_string(&self.survival);
-        f.write_str(&buf)?;
-        Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct ParseRuleError;
+/// An error which can be returned when parsing a rule.
+///
+/// The reason of the error is returned by [`kind()`].
+///
+/// [`kind()`]: #method.kind
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{ParseRuleErrorKind, Rule};
+/// let err = "B9/S23".parse::<Rule>().unwrap_err();
+/// assert_eq!(err.kind(), ParseRuleErrorKind::DigitOutOfRange);
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseRuleError {
+    kind: ParseRuleErrorKind,
+}
+
+/// The reason of [`ParseRuleError`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseRuleErrorKind {
+    /// The string has no separator `'/'`, e.g., `"B3S23"`.
+    NoSeparator,
+    /// The string has more than two fields separated by `'/'`, e.g., `"B3/S23/C2"`.
+    TooManyFields,
+    /// The labels of the fields are neither `"B"` and `"S"` (in either order) nor empty, e.g., `"B3/23"`.
+    UnknownLabel,
+    /// A number of neighbors is greater than 8, e.g., `"B9/S23"`.
+    DigitOutOfRange,
+    /// A character that is not a digit is found in the numbers, e.g., `"B3/S2x"`.
+    InvalidChar,
+}
+
+impl ParseRuleError {
+    /// Returns the reason of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{ParseRuleErrorKind, Rule};
+    /// let err = "B3S23".parse::<Rule>().unwrap_err();
+    /// assert_eq!(err.kind(), ParseRuleErrorKind::NoSeparator);
+    /// ```
+    ///
+    #[inline]
+    pub const fn kind(&self) -> ParseRuleErrorKind {
+        self.kind
+    }
+}
 
 impl Error for ParseRuleError {}
 
 impl fmt::Display for ParseRuleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str("cannot parse rule from the string")
+        let reason = match self.kind {
+            ParseRuleErrorKind::NoSeparator => "no separator '/' found",
+            ParseRuleErrorKind::TooManyFields => "too many fields separated by '/'",
+            ParseRuleErrorKind::UnknownLabel => "unknown labels of the fields",
+            ParseRuleErrorKind::DigitOutOfRange => "number of neighbors out of range (max 8)",
+            ParseRuleErrorKind::InvalidChar => "invalid character in the numbers",
+        };
+        write!(f, "cannot parse rule from the string: {reason}")
+    }
+}
+
+impl From<ParseRuleErrorKind> for ParseRuleError {
+    #[inline]
+    fn from(kind: ParseRuleErrorKind) -> Self {
+        Self { kind }
     }
 }
 
 impl FromStr for Rule {
     type Err = ParseRuleError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn convert_numbers_to_slice(numbers: &str) -> Option<[bool; TRUTH_TABLE_SIZE]> {
+        fn convert_numbers_to_slice(numbers: &str) -> Result<[bool; TRUTH_TABLE_SIZE], ParseRuleErrorKind> {
             numbers.chars().try_fold([false; TRUTH_TABLE_SIZE], |mut buf, c| {
-                let n = c.to_digit(TRUTH_TABLE_SIZE as u32)? as usize;
+                let n = c.to_digit(10).ok_or(ParseRuleErrorKind::InvalidChar)? as usize;
+                if n >= TRUTH_TABLE_SIZE {
+                    return Err(ParseRuleErrorKind::DigitOutOfRange);
+                }
                 buf[n] = true;
-                Some(buf)
+                Ok(buf)
             })
         }
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect(); // whitespaces are tolerated, e.g., "B3 / S23"
         let fields_splitted: Vec<_> = s.split('/').collect();
-        if fields_splitted.len() != 2 {
-            return Err(ParseRuleError);
+        match fields_splitted.len() {
+            0 | 1 => return Err(ParseRuleErrorKind::NoSeparator.into()),
+            2 => {}
+            _ => return Err(ParseRuleErrorKind::TooManyFields.into()),
         }
         let (labels, numbers): (Vec<_>, Vec<_>) = fields_splitted
             .iter()
             .map(|s| s.split_at(s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len())))
             .unzip();
-        let numbers = if labels.iter().zip(["B", "S"]).all(|(lhs, rhs)| lhs.eq_ignore_ascii_case(rhs)) {
+        let is_labeled = |expected: [&str; 2]| labels.iter().zip(expected).all(|(lhs, rhs)| lhs.eq_ignore_ascii_case(rhs));
+        let numbers = if is_labeled(["B", "S"]) {
             // the birth/survival notation, e.g., "B3/S23"
             numbers
+        } else if is_labeled(["S", "B"]) {
+            // the birth/survival notation with the survival field first, e.g., "S23/B3"
+            vec![numbers[1], numbers[0]]
         } else if labels.iter().all(|s| s.is_empty()) {
             // S/B notation, e.g., "23/3"
             vec![numbers[1], numbers[0]]
         } else {
-            return Err(ParseRuleError);
+            return Err(ParseRuleErrorKind::UnknownLabel.into());
         };
-        let Some(slices) = numbers
-            .into_iter()
-            .map(convert_numbers_to_slice)
-            .collect::<Option<Vec<_>>>() else {
-            return Err(ParseRuleError);
-        };
-        Ok(Self {
-            birth: slices[0],
-            survival: slices[1],
-        })
+        let slices = numbers.into_iter().map(convert_numbers_to_slice).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(&slices[0], &slices[1]))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rule {
+    /// Serializes the rule as the string in the birth/survival notation, e.g., `"B3/S23"`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rule {
+    /// Deserializes the rule from the string accepted by [`from_str()`].
+    ///
+    /// [`from_str()`]: #method.from_str
+    ///
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -224,8 +861,8 @@ mod tests {
     );
     fn check_value(target: &Rule, expected_birth: &[usize], expected_survival: &[usize]) {
         for i in 0..=8 {
-            assert_eq!(target.is_born(i), expected_birth.iter().any(|&x| x == i));
-            assert_eq!(target.is_survive(i), expected_survival.iter().any(|&x| x == i));
+            assert_eq!(target.is_born(i), expected_birth.contains(&i));
+            assert_eq!(target.is_survive(i), expected_survival.contains(&i));
         }
     }
     #[test]
@@ -250,6 +887,126 @@ mod tests {
         check_value(&target, &[3], &[2, 3]);
     }
     #[test]
+    fn is_born_checked() {
+        let target = Rule::conways_life();
+        for i in 0..=8 {
+            assert_eq!(target.is_born_checked(i), Some(target.is_born(i)));
+        }
+        assert_eq!(target.is_born_checked(9), None);
+    }
+    #[test]
+    fn is_survive_checked() {
+        let target = Rule::conways_life();
+        for i in 0..=8 {
+            assert_eq!(target.is_survive_checked(i), Some(target.is_survive(i)));
+        }
+        assert_eq!(target.is_survive_checked(9), None);
+    }
+    #[test]
+    fn to_transition_table_conways_life() {
+        let target = Rule::conways_life();
+        let result = target.to_transition_table();
+        assert!(!result[0b000_010_000]);
+        assert!(result[0b100_010_001]);
+        assert!(result[0b101_010_100]);
+        assert!(!result[0b111_110_000]);
+        assert!(!result[0b000_000_000]);
+        assert!(result[0b001_000_011]);
+        assert!(!result[0b011_000_011]);
+        assert_eq!(result.iter().filter(|&&x| x).count(), 140); // C(8,3) births + C(8,2) + C(8,3) survivals
+    }
+    #[test]
+    fn transition_diff_conways_life_highlife() {
+        let target = Rule::conways_life();
+        assert_eq!(target.transition_diff(&RULE_HIGHLIFE), vec![(true, 6)]);
+        assert_eq!(RULE_HIGHLIFE.transition_diff(&target), vec![(true, 6)]);
+    }
+    #[test]
+    fn transition_diff_same() {
+        let target = Rule::conways_life();
+        assert!(target.transition_diff(&Rule::conways_life()).is_empty());
+    }
+    #[test]
+    fn hamming_distance() {
+        let target = Rule::conways_life();
+        assert_eq!(target.hamming_distance(&RULE_HIGHLIFE), 1);
+        assert_eq!(target.hamming_distance(&Rule::conways_life()), 0);
+        assert_eq!(target.hamming_distance(&RULE_HIGHLIFE), target.transition_diff(&RULE_HIGHLIFE).len());
+    }
+    #[test]
+    fn from_fn_conways_life() {
+        let target = Rule::from_fn(|alive, count| count == 3 || (alive && count == 2));
+        assert_eq!(target, Rule::conways_life());
+    }
+    #[test]
+    fn from_fn_constant() {
+        assert_eq!(Rule::from_fn(|_, _| false).to_string(), "B/S");
+        assert_eq!(Rule::from_fn(|_, _| true).to_string(), "B012345678/S012345678");
+    }
+    #[test]
+    fn enumerate_counts() {
+        assert_eq!(Rule::enumerate(1, 0).count(), 9);
+        assert_eq!(Rule::enumerate(0, 0).count(), 1);
+        assert_eq!(Rule::enumerate(9, 9).count(), 1);
+        assert_eq!(Rule::enumerate(10, 0).count(), 0);
+        let total: usize = (0..=9).flat_map(|b| (0..=9).map(move |s| Rule::enumerate(b, s).count())).sum();
+        assert_eq!(total, 1 << 18);
+    }
+    #[test]
+    fn enumerate_birth_only() {
+        let result: Vec<_> = Rule::enumerate(1, 0).collect();
+        for (i, rule) in result.iter().enumerate() {
+            check_value(rule, &[i], &[]);
+        }
+    }
+    #[test]
+    fn masks_conways_life() {
+        let target = Rule::conways_life();
+        assert_eq!(target.birth_mask(), 0b000001000);
+        assert_eq!(target.survival_mask(), 0b000001100);
+    }
+    #[test]
+    fn neighbor_thresholds_conways_life() {
+        let target = Rule::conways_life();
+        assert_eq!(target.min_birth_neighbors(), Some(3));
+        assert_eq!(target.max_birth_neighbors(), Some(3));
+        assert_eq!(target.min_survival_neighbors(), Some(2));
+        assert_eq!(target.max_survival_neighbors(), Some(3));
+    }
+    #[test]
+    fn neighbor_thresholds_others() -> Result<()> {
+        assert_eq!(RULE_HIGHLIFE.min_birth_neighbors(), Some(3));
+        assert_eq!(RULE_HIGHLIFE.max_birth_neighbors(), Some(6));
+        let target: Rule = "B0/S012345678".parse()?;
+        assert_eq!(target.min_birth_neighbors(), Some(0));
+        assert_eq!(target.max_birth_neighbors(), Some(0));
+        assert_eq!(target.min_survival_neighbors(), Some(0));
+        assert_eq!(target.max_survival_neighbors(), Some(8));
+        let target: Rule = "B/S".parse()?;
+        assert_eq!(target.min_birth_neighbors(), None);
+        assert_eq!(target.max_survival_neighbors(), None);
+        Ok(())
+    }
+    #[test]
+    fn masks_highlife() {
+        assert_eq!(RULE_HIGHLIFE.birth_mask(), 0b001001000);
+        assert_eq!(RULE_HIGHLIFE.survival_mask(), 0b000001100);
+    }
+    #[test]
+    fn is_stable_background() -> Result<()> {
+        assert!(Rule::conways_life().is_stable_background());
+        assert!(RULE_HIGHLIFE.is_stable_background());
+        for s in ["B0/S", "B0/S012345678", "B012345678/S"] {
+            assert!(!s.parse::<Rule>()?.is_stable_background());
+        }
+        Ok(())
+    }
+    #[test]
+    fn transition_count() {
+        assert_eq!(Rule::conways_life().transition_count(), 3);
+        assert_eq!(RULE_HIGHLIFE.transition_count(), 4);
+    }
+    #[test]
     fn display_conways_life() {
         let target = Rule::conways_life();
         assert_eq!(target.to_string(), "B3/S23");
@@ -260,12 +1017,80 @@ mod tests {
         assert_eq!(target.to_string(), "B36/S23");
     }
     #[test]
+    fn display_alternate() -> Result<()> {
+        let target = Rule::conways_life();
+        assert_eq!(format!("{target}"), "B3/S23");
+        assert_eq!(format!("{target:#}"), "Birth: {3}, Survival: {2, 3}");
+        let target: Rule = "B/S012".parse()?;
+        assert_eq!(format!("{target:#}"), "Birth: {}, Survival: {0, 1, 2}");
+        Ok(())
+    }
+    #[test]
+    fn counts_highlife() {
+        assert!(RULE_HIGHLIFE.birth_counts().eq([3, 6]));
+        assert!(RULE_HIGHLIFE.survival_counts().eq([2, 3]));
+    }
+    #[test]
+    fn as_str_same_as_display() -> Result<()> {
+        for s in ["B3/S23", "B36/S23", "B/S", "B012345678/S012345678", "B2/S", "B0/S8"] {
+            let target: Rule = s.parse()?;
+            assert_eq!(target.as_str(), s);
+            assert_eq!(target.as_str(), target.to_string());
+        }
+        assert_eq!(RULE_HIGHLIFE.as_str(), RULE_HIGHLIFE.to_string());
+        Ok(())
+    }
+    #[test]
+    fn to_s_b_string_conways_life() -> Result<()> {
+        let target = Rule::conways_life();
+        let result = target.to_s_b_string();
+        assert_eq!(result, "23/3");
+        assert_eq!(result.parse::<Rule>()?, target);
+        Ok(())
+    }
+    #[test]
+    fn to_s_b_string_highlife() -> Result<()> {
+        let target = RULE_HIGHLIFE;
+        let result = target.to_s_b_string();
+        assert_eq!(result, "23/36");
+        assert_eq!(result.parse::<Rule>()?, target);
+        Ok(())
+    }
+    #[test]
+    fn from_name_known() {
+        assert_eq!(Rule::from_name("Life"), Some(Rule::conways_life()));
+        assert_eq!(Rule::from_name("Conway's Life"), Some(Rule::conways_life()));
+        assert_eq!(Rule::from_name("HighLife"), Some(RULE_HIGHLIFE));
+        assert_eq!(Rule::from_name(" high-life "), Some(RULE_HIGHLIFE));
+        assert_eq!(Rule::from_name("Day & Night").map(|rule| rule.to_string()), Some("B3678/S34678".to_string()));
+    }
+    #[test]
+    fn from_name_unknown() {
+        assert_eq!(Rule::from_name("unknown"), None);
+        assert_eq!(Rule::from_name("B3/S23"), None);
+    }
+    #[test]
     fn from_str_birth_survival_notation() -> Result<()> {
         let target: Rule = "B3/S23".parse()?;
         check_value(&target, &[3], &[2, 3]);
         Ok(())
     }
     #[test]
+    fn from_str_birth_survival_notation_survival_first() -> Result<()> {
+        let target: Rule = "S23/B3".parse()?;
+        assert_eq!(target, Rule::conways_life());
+        let target: Rule = "s23/b36".parse()?;
+        check_value(&target, &[3, 6], &[2, 3]);
+        let target: Rule = "B3/S23".parse()?;
+        assert_eq!(target, Rule::conways_life());
+        Ok(())
+    }
+    #[test]
+    fn from_str_labels_same() {
+        let target = "B3/B23".parse::<Rule>();
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::UnknownLabel));
+    }
+    #[test]
     fn from_str_s_b_notation() -> Result<()> {
         let target: Rule = "23/3".parse()?;
         check_value(&target, &[3], &[2, 3]);
@@ -296,28 +1121,82 @@ mod tests {
         Ok(())
     }
     #[test]
+    fn from_str_birth_survival_notation_with_whitespaces() -> Result<()> {
+        let target: Rule = "B3 / S23".parse()?;
+        assert_eq!(target, Rule::conways_life());
+        Ok(())
+    }
+    #[test]
+    fn from_str_birth_survival_notation_duplicate_numbers() -> Result<()> {
+        let target: Rule = "B33/S2233".parse()?;
+        assert_eq!(target, Rule::conways_life());
+        Ok(())
+    }
+    #[test]
+    fn from_str_s_b_notation_with_whitespaces() -> Result<()> {
+        let target: Rule = " 23 / 3 ".parse()?;
+        assert_eq!(target, Rule::conways_life());
+        Ok(())
+    }
+    #[test]
+    fn from_str_invalid_number() {
+        let target = "B3/S2x3".parse::<Rule>();
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::InvalidChar));
+    }
+    #[test]
     fn from_str_no_separator() {
         let target = "B0S0".parse::<Rule>();
-        assert!(target.is_err());
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::NoSeparator));
     }
     #[test]
     fn from_str_too_many_separators() {
         let target = "B0/S0/C0".parse::<Rule>();
-        assert!(target.is_err());
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::TooManyFields));
     }
     #[test]
     fn from_str_no_label_birth() {
         let target = "0/S0".parse::<Rule>();
-        assert!(target.is_err());
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::UnknownLabel));
     }
     #[test]
     fn from_str_no_label_survival() {
         let target = "B0/0".parse::<Rule>();
-        assert!(target.is_err());
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::UnknownLabel));
+    }
+    #[test]
+    fn from_str_s_b_notation_too_large_number() {
+        let target = "23/9".parse::<Rule>();
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::DigitOutOfRange));
+    }
+    #[test]
+    fn from_str_unknown_label() {
+        let target = "B3/C23".parse::<Rule>();
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::UnknownLabel));
+    }
+    #[test]
+    fn from_str_error_display() {
+        let target = "B9/S0".parse::<Rule>();
+        assert!(matches!(target, Err(e) if e.to_string().contains("max 8")));
     }
     #[test]
     fn from_str_birth_survival_notation_too_large_number() {
         let target = "B9/S0".parse::<Rule>();
+        assert!(matches!(target, Err(e) if e.kind() == ParseRuleErrorKind::DigitOutOfRange));
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() -> Result<()> {
+        let target = Rule::conways_life();
+        let serialized = serde_json::to_string(&target)?;
+        assert_eq!(serialized, "\"B3/S23\"");
+        let deserialized: Rule = serde_json::from_str(&serialized)?;
+        assert_eq!(deserialized, target);
+        Ok(())
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_deserialize_invalid() {
+        let target = serde_json::from_str::<Rule>("\"B3/S2x3\"");
         assert!(target.is_err());
     }
 }
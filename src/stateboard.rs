@@ -0,0 +1,201 @@
+use fnv::FnvBuildHasher;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+use crate::Position;
+
+/// A two-dimensional orthogonal grid map of multi-state cells, such as the cells of [Generations](https://conwaylife.com/wiki/Generations) rules.
+///
+/// The type parameter `T` is used as the type of the x- and y-coordinate values for each cell.
+///
+/// Each cell has a state of `u8`, and the state `0` means a dead cell.
+/// Only the cells with non-zero states are held, so setting the state `0` removes the cell from the board.
+/// For the cells having only two states, use [`Board`] instead.
+///
+/// [`Board`]: crate::Board
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Position, StateBoard};
+/// let mut board = StateBoard::<i16>::new();
+/// board.set_state(Position(0, 0), 1);
+/// board.set_state(Position(1, 0), 2);
+/// assert_eq!(board.state(&Position(0, 0)), 1);
+/// assert_eq!(board.state(&Position(1, 0)), 2);
+/// assert_eq!(board.state(&Position(2, 0)), 0);
+/// assert_eq!(board.iter_states().count(), 2);
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StateBoard<T>(HashMap<Position<T>, u8, FnvBuildHasher>)
+where
+    T: Eq + Hash;
+
+// Inherent methods
+
+impl<T> StateBoard<T>
+where
+    T: Eq + Hash,
+{
+    /// Creates an empty board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::StateBoard;
+    /// let board = StateBoard::<i16>::new();
+    /// assert_eq!(board.iter_states().count(), 0);
+    /// ```
+    ///
+    #[inline]
+    pub fn new() -> Self {
+        Self(HashMap::default())
+    }
+
+    /// Returns the state of the specified position, or `0` if the cell is dead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Position, StateBoard};
+    /// let mut board = StateBoard::<i16>::new();
+    /// board.set_state(Position(0, 0), 3);
+    /// assert_eq!(board.state(&Position(0, 0)), 3);
+    /// assert_eq!(board.state(&Position(0, 1)), 0);
+    /// ```
+    ///
+    #[inline]
+    pub fn state(&self, position: &Position<T>) -> u8 {
+        self.0.get(position).copied().unwrap_or(0)
+    }
+
+    /// Sets the state of the specified position, and returns the previous state.
+    ///
+    /// Setting the state `0` removes the cell from the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Position, StateBoard};
+    /// let mut board = StateBoard::<i16>::new();
+    /// assert_eq!(board.set_state(Position(0, 0), 1), 0);
+    /// assert_eq!(board.set_state(Position(0, 0), 2), 1);
+    /// assert_eq!(board.set_state(Position(0, 0), 0), 2);
+    /// assert_eq!(board.iter_states().count(), 0);
+    /// ```
+    ///
+    pub fn set_state(&mut self, position: Position<T>, state: u8) -> u8 {
+        let prev = if state == 0 {
+            self.0.remove(&position)
+        } else {
+            self.0.insert(position, state)
+        };
+        prev.unwrap_or(0)
+    }
+
+    /// Removes all cells from the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Position, StateBoard};
+    /// let mut board = StateBoard::<i16>::new();
+    /// board.set_state(Position(0, 0), 1);
+    /// board.clear();
+    /// assert_eq!(board.iter_states().count(), 0);
+    /// ```
+    ///
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Creates an iterator over the series of the positions and the states of non-dead cells, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Position, StateBoard};
+    /// let board: StateBoard<i16> = [(Position(0, 0), 1), (Position(1, 0), 2)].iter().copied().collect();
+    /// let mut result: Vec<_> = board.iter_states().collect();
+    /// result.sort_unstable_by_key(|&(_, state)| state);
+    /// assert_eq!(result, vec![(Position(0, 0), 1), (Position(1, 0), 2)]);
+    /// ```
+    ///
+    pub fn iter_states(&self) -> impl Iterator<Item = (Position<T>, u8)> + '_
+    where
+        T: Copy,
+    {
+        self.0.iter().map(|(&position, &state)| (position, state))
+    }
+}
+
+// Trait implementations
+
+impl<T> Default for StateBoard<T>
+where
+    T: Eq + Hash,
+{
+    /// Returns the default value of the type, same as the return value of [`new()`].
+    ///
+    /// [`new()`]: #method.new
+    ///
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Position<T>, u8)> for StateBoard<T>
+where
+    T: Eq + Hash,
+{
+    /// Creates a value from an owning iterator over a series of pairs of the position and the state.
+    /// The pairs with the state `0` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Position, StateBoard};
+    /// let board: StateBoard<i16> = [(Position(0, 0), 1), (Position(1, 0), 0)].iter().copied().collect();
+    /// assert_eq!(board.iter_states().count(), 1);
+    /// ```
+    ///
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Position<T>, u8)>,
+    {
+        let mut board = Self::new();
+        for (position, state) in iter {
+            board.set_state(position, state);
+        }
+        board
+    }
+}
+
+// Unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn default() {
+        let target = StateBoard::<i16>::default();
+        assert_eq!(target, StateBoard::new());
+    }
+    #[test]
+    fn iter_states_two_states() {
+        let mut target = StateBoard::<i16>::new();
+        target.set_state(Position(0, 0), 1);
+        target.set_state(Position(1, 0), 2);
+        target.set_state(Position(2, 0), 2);
+        let mut result: Vec<_> = target.iter_states().collect();
+        result.sort_unstable_by_key(|&(Position(x, y), _)| (y, x));
+        assert_eq!(result, vec![(Position(0, 0), 1), (Position(1, 0), 2), (Position(2, 0), 2)]);
+        target.set_state(Position(1, 0), 0);
+        assert_eq!(target.iter_states().count(), 2);
+        assert_eq!(target.state(&Position(1, 0)), 0);
+    }
+}
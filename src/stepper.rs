@@ -0,0 +1,85 @@
+use std::hash::Hash;
+
+use crate::Board;
+
+/// Provides methods for advancing the generation of a board, shared by simulation backends.
+///
+/// The type parameter `T` is used as the type of the x- and y-coordinate values for each cell.
+///
+/// Implementors only need to provide [`board()`] and [`advance()`].
+/// The default implementation of [`advance_by()`] repeats [`advance()`], and implementors that can jump many generations at once,
+/// e.g., a [HashLife](https://conwaylife.com/wiki/HashLife) backend advancing `2^k` generations in a step, may override it.
+///
+/// [`board()`]: #tymethod.board
+/// [`advance()`]: #tymethod.advance
+/// [`advance_by()`]: #method.advance_by
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Board, Game, Position, Rule, Stepper};
+/// fn population_after<S: Stepper<i16>>(stepper: &mut S, steps: usize) -> usize {
+///     Stepper::advance_by(stepper, steps);
+///     stepper.board().iter().count()
+/// }
+/// let rule = Rule::conways_life();
+/// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+/// let mut game = Game::new(rule, board);
+/// assert_eq!(population_after(&mut game, 3), 3);
+/// ```
+///
+pub trait Stepper<T>
+where
+    T: Eq + Hash,
+{
+    /// Returns the current board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule, Stepper};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(1, 0), Position(0, 1)].iter().collect();
+    /// let game = Game::new(rule, board.clone());
+    /// assert_eq!(Stepper::board(&game), &board);
+    /// ```
+    ///
+    fn board(&self) -> &Board<T>;
+
+    /// Advances the board by one generation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule, Stepper};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board.clone());
+    /// Stepper::advance(&mut game);
+    /// assert_ne!(Stepper::board(&game), &board);
+    /// ```
+    ///
+    fn advance(&mut self);
+
+    /// Advances the board by the specified number of generations, and returns the number of generations actually advanced.
+    ///
+    /// Implementors may stop advancing early when further generations are known not to change the board, e.g., after the pattern dies out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use life_backend::{Board, Game, Position, Rule, Stepper};
+    /// let rule = Rule::conways_life();
+    /// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+    /// let mut game = Game::new(rule, board.clone());
+    /// assert_eq!(Stepper::advance_by(&mut game, 2), 2);
+    /// assert_eq!(Stepper::board(&game), &board);
+    /// ```
+    ///
+    fn advance_by(&mut self, steps: usize) -> usize {
+        for _ in 0..steps {
+            self.advance();
+        }
+        steps
+    }
+}
@@ -0,0 +1,85 @@
+use fnv::FnvBuildHasher;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{Position, Rule};
+
+/// Provides methods for storing live cells, used by [`Game`] to hold the board.
+///
+/// The type parameter `T` is used as the type of the x- and y-coordinate values for each cell.
+///
+/// Implementors only need to provide the basic operations of a set of live cells and the enumeration of neighbours.
+/// [`Board`] is the default implementation, and other representations, such as a dense bitset or a quadtree,
+/// can be used with the same [`Game`] logic through [`Game::new_with_storage()`].
+///
+/// [`Game`]: crate::Game
+/// [`Board`]: crate::Board
+/// [`Game::new_with_storage()`]: crate::Game::new_with_storage
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Board, CellStorage, Position};
+/// let mut storage = Board::<i16>::new();
+/// CellStorage::insert(&mut storage, Position(0, 0));
+/// assert!(CellStorage::contains(&storage, &Position(0, 0)));
+/// assert_eq!(CellStorage::moore_neighbors(&storage, &Position(0, 0)).count(), 8);
+/// ```
+///
+pub trait CellStorage<T>: Default {
+    /// Returns `true` if the storage contains the specified position as a live cell.
+    fn contains(&self, position: &Position<T>) -> bool;
+
+    /// Adds the specified position as a live cell, and returns whether it was newly added.
+    fn insert(&mut self, position: Position<T>) -> bool;
+
+    /// Creates an iterator over the live cell positions in arbitrary order.
+    fn iter(&self) -> Box<dyn Iterator<Item = Position<T>> + '_>;
+
+    /// Removes all live cells.
+    fn clear(&mut self);
+
+    /// Creates an iterator over the positions in [Moore neighbourhood](https://conwaylife.com/wiki/Moore_neighbourhood) of the specified position.
+    ///
+    /// Positions that the storage cannot hold, e.g., positions beyond the bounds of the coordinate type, are excluded.
+    fn moore_neighbors(&self, position: &Position<T>) -> Box<dyn Iterator<Item = Position<T>> + '_>;
+
+    /// Retains only the live cells specified by the predicate.
+    ///
+    /// The default implementation rebuilds the storage from the retained live cells.
+    fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&Position<T>) -> bool,
+    {
+        let retained: Vec<_> = self.iter().filter(|pos| pred(pos)).collect();
+        self.clear();
+        for pos in retained {
+            self.insert(pos);
+        }
+    }
+
+    /// Writes the live cells of the next generation into `dst`, based on the specified rule.
+    ///
+    /// The default implementation counts live neighbours of every live cell and its neighbours using [`moore_neighbors()`].
+    ///
+    /// [`moore_neighbors()`]: #tymethod.moore_neighbors
+    fn step_into(&self, rule: &Rule, dst: &mut Self)
+    where
+        T: Copy + Eq + Hash,
+    {
+        let mut counts: HashMap<_, usize, FnvBuildHasher> = HashMap::default();
+        for pos in self.iter() {
+            counts.entry(pos).or_insert(0);
+            for neighbor in self.moore_neighbors(&pos) {
+                *counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        dst.clear();
+        for (pos, count) in counts {
+            let alive = if self.contains(&pos) { rule.is_survive(count) } else { rule.is_born(count) };
+            if alive {
+                dst.insert(pos);
+            }
+        }
+    }
+}
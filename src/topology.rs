@@ -0,0 +1,40 @@
+use crate::BoardRange;
+
+/// A topology of the plane where a game is played.
+///
+/// The type parameter `T` is used as the type of the x- and y-coordinate values for each cell.
+///
+/// # Examples
+///
+/// ```
+/// use life_backend::{Board, BoardRange, Game, Position, Rule, Topology};
+/// let rule = Rule::conways_life();
+/// let board: Board<i16> = [Position(0, 1), Position(1, 1), Position(2, 1)].iter().collect(); // Blinker pattern
+/// let region: BoardRange<_> = [Position(0, 0), Position(2, 1)].iter().collect();
+/// let mut game = Game::new_with_topology(rule, board, Topology::Bounded(region));
+/// game.advance();
+/// assert_eq!(game.board().iter().count(), 2); // the cell at (1, 2) is outside the region
+/// ```
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology<T> {
+    /// The infinite plane, where the board has no boundary.
+    Infinite,
+    /// The plane bounded by the specified region.
+    /// Cells outside the region are always dead, i.e., cells leaving the region are discarded every generation.
+    Bounded(BoardRange<T>),
+}
+
+// Trait implementations
+
+impl<T> Default for Topology<T> {
+    /// Returns the default value of the type, [`Infinite`].
+    ///
+    /// [`Infinite`]: Topology::Infinite
+    ///
+    #[inline]
+    fn default() -> Self {
+        Self::Infinite
+    }
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use std::path::Path;
 
 use life_backend::format;
-use life_backend::{Board, Game, Position};
+use life_backend::{Game, PatternKind};
 
 use i16 as I;
 
@@ -12,7 +12,7 @@ where
 {
     let handler = format::open(path)?;
     let rule = handler.rule();
-    let board = handler.live_cells().map(Position::try_from).collect::<Result<Board<_>, _>>()?;
+    let board = format::to_board(handler.as_ref())?;
     let game = Game::new(rule, board);
     Ok(game)
 }
@@ -31,71 +31,44 @@ fn advance_game(mut game: Game<I>, steps: usize) -> Game<I> {
     game
 }
 
-fn advance_game_with_check(mut game: Game<I>, steps: usize, init: &Board<I>) -> Game<I> {
-    for i in 0..steps {
-        if i > 0 {
-            assert_ne!(game.board(), init);
-        }
-        game.advance();
-    }
-    game
-}
-
-fn shift_board(board: &Board<I>, relative_position: (I, I)) -> Board<I> {
-    let board: Board<_> = board
-        .iter()
-        .map(|pos| Position(pos.0 + relative_position.0, pos.1 + relative_position.1))
-        .collect();
-    board
-}
-
-fn do_oscillator_test<P>(path: P, period: usize) -> Result<()>
+fn do_periodic_test<P>(path: P, kind: PatternKind, period: usize, relative_position: (I, I)) -> Result<()>
 where
     P: AsRef<Path>,
 {
     // Load the given file and create a game
-    let game = load_game(path)?;
+    let mut game = load_game(path)?;
     print_game(&game, 0);
 
-    // Set the initial pattern to the variable
-    let init = game.board().to_owned();
-
-    // Advance the game to the target generation
-    let game = advance_game_with_check(game, period, &init);
-    print_game(&game, period);
+    // Advance the game until the pattern returns to a translation of the initial state
+    let certificate = game.classify_with_certificate(period)?;
+    print_game(&game, certificate.period());
 
     // Check the result
-    let result = game.board();
-    assert_eq!(result, &init);
+    assert_eq!(certificate.kind(), kind);
+    assert_eq!(certificate.period(), period);
+    assert_eq!(certificate.displacement(), (relative_position.0 as i64, relative_position.1 as i64));
     Ok(())
 }
 
+fn do_oscillator_test<P>(path: P, period: usize) -> Result<()>
+where
+    P: AsRef<Path>,
+{
+    do_periodic_test(path, PatternKind::Oscillator, period, (0, 0))
+}
+
 fn do_stilllife_test<P>(path: P) -> Result<()>
 where
     P: AsRef<Path>,
 {
-    do_oscillator_test(path, 1)
+    do_periodic_test(path, PatternKind::StillLife, 1, (0, 0))
 }
 
 fn do_spaceship_test<P>(path: P, period: usize, relative_position: (I, I)) -> Result<()>
 where
     P: AsRef<Path>,
 {
-    // Load the given file and create a game
-    let game = load_game(path)?;
-    print_game(&game, 0);
-
-    // Set the expected pattern to the variable
-    let expected = shift_board(game.board(), relative_position);
-
-    // Advance the game to the target generation
-    let game = advance_game(game, period);
-    print_game(&game, period);
-
-    // Check the result
-    let result = game.board();
-    assert_eq!(result, &expected);
-    Ok(())
+    do_periodic_test(path, PatternKind::Spaceship, period, relative_position)
 }
 
 fn do_methuselah_test<P>(path: P, steps: usize, expected_final_population: usize) -> Result<()>
@@ -120,7 +93,18 @@ fn do_diehard_test<P>(path: P, steps: usize) -> Result<()>
 where
     P: AsRef<Path>,
 {
-    do_methuselah_test(path, steps, 0)
+    // Load the given file and create a game
+    let mut game = load_game(path)?;
+    print_game(&game, 0);
+
+    // Advance the game beyond the target generation, and it stops when the pattern dies out
+    let generation = game.advance_by(steps * 2);
+    print_game(&game, generation);
+
+    // Check the result
+    assert_eq!(generation, steps);
+    assert_eq!(game.board().iter().count(), 0);
+    Ok(())
 }
 
 macro_rules! create_stilllife_test_function {